@@ -0,0 +1,30 @@
+use super::*;
+
+///
+///
+/// ```sdl
+/// #$name
+/// #$name($arguments)
+/// #[$name, $name($arguments)]
+/// $statement
+/// ```
+///
+/// Annotations decorate the value defined by the statement, the one closest to the statement goes first.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AnnotationStatement {
+    pub annotations: Vec<Annotation>,
+    pub statement: ASTNode,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Annotation {
+    pub name: ASTNode,
+    pub arguments: CallApply,
+    pub range: Range,
+}
+
+impl Annotation {
+    pub fn name(&self) -> String {
+        self.name.as_string()
+    }
+}
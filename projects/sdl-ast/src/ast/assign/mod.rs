@@ -0,0 +1,27 @@
+use super::*;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AssignKind {
+    /// eg: let x = 1
+    Let,
+    /// eg: var x = 1
+    Var,
+    /// eg: x = 1
+    Set,
+}
+
+///
+///
+/// ```sdl
+/// let $pattern = $value
+/// var [a, b] = $value
+/// (a, b) = $value
+/// ```
+///
+/// A `pattern` is either a `Symbol` or a `List` of `Symbol`s to destructure.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Assign {
+    pub kind: AssignKind,
+    pub pattern: ASTNode,
+    pub value: ASTNode,
+}
@@ -38,30 +38,78 @@ impl ASTKind {
     }
     pub fn is_empty(&self) -> bool {
         match self {
-            // Self::String(_) => {
-            //     unimplemented!()
-            // }
-            Self::List(v) => v.is_empty(),
-            // Self::Dict(v) => {
-            //     v.is_empty()
-            // }
+            Self::String(v) | Self::HTMLText(v) => v.is_empty(),
+            Self::List(v) | Self::Block(v) => v.is_empty(),
+            Self::Dict(v) => v.is_empty(),
             _ => false,
         }
     }
-    pub fn is_falsy(&self) -> bool {
+    /// Whether `if`, `and`, `or`, `!` and guards treat the value as true.
+    ///
+    /// `null`, `false`, zero, and empty strings, lists and dicts are falsy, everything else is truthy.
+    pub fn is_truthy(&self) -> bool {
         match self {
-            Self::Null => true,
-            Self::Boolean(false) => true,
-            // Self::String(_) => {
-            //     unimplemented!()
-            // }
-            Self::Integer(v) => v.is_zero(),
-            Self::Decimal(v) => v.is_zero(),
-            Self::List(v) => v.is_empty(),
-            // Self::Dict(v) => {
-            //     v.is_empty()
-            // }
+            Self::Null | Self::None => false,
+            Self::Boolean(v) => *v,
+            Self::Integer(_) | Self::Decimal(_) => !self.is_zero(),
+            Self::String(_) | Self::HTMLText(_) | Self::List(_) | Self::Block(_) | Self::Dict(_) => !self.is_empty(),
             _ => true,
         }
     }
+    pub fn is_falsy(&self) -> bool {
+        !self.is_truthy()
+    }
+}
+
+impl ASTNode {
+    /// Whether this node or any of its children contains a `yield`, bodies of nested functions are not searched.
+    pub fn has_yield(&self) -> bool {
+        match &self.kind {
+            ASTKind::ControlFlow(e) => e.kind == ControlKind::Yield || e.value.as_ref().is_some_and(|e| e.has_yield()),
+            ASTKind::Program(v) | ASTKind::Block(v) | ASTKind::Statement(v) | ASTKind::List(v) => any_yield(v),
+            ASTKind::IfElseChain(e) => {
+                e.pairs.iter().any(|(cond, act)| cond.has_yield() || act.has_yield())
+                    || e.cover.as_ref().is_some_and(|e| e.has_yield())
+            }
+            ASTKind::ForInLoop(e) => {
+                e.terms.has_yield()
+                    || e.guard.as_ref().is_some_and(|e| e.has_yield())
+                    || e.block.has_yield()
+                    || e.for_else.as_ref().is_some_and(|e| e.has_yield())
+            }
+            ASTKind::Assign(e) => e.value.has_yield(),
+            ASTKind::ClassStatement(e) => e.body.has_yield(),
+            ASTKind::ExtendStatement(e) => e.body.has_yield(),
+            ASTKind::AnnotationStatement(e) => {
+                e.annotations.iter().any(|a| a.arguments.has_yield()) || e.statement.has_yield()
+            }
+            ASTKind::Expression(e, _) => e.has_yield(),
+            ASTKind::CallChain(e) => e.base.has_yield() || any_yield(&e.chain),
+            ASTKind::CallApply(e) => e.has_yield(),
+            ASTKind::CallDot(e) => e.apply.as_ref().is_some_and(|e| e.has_yield()),
+            ASTKind::CallSlice(e) => e.indexes.iter().any(|index| match index {
+                SliceIndex::Index(e) => e.has_yield(),
+                SliceIndex::Range { start, end, step } => {
+                    [start, end, step].iter().any(|e| e.as_ref().is_some_and(|e| e.has_yield()))
+                }
+            }),
+            ASTKind::InfixExpression(e) => e.lhs.has_yield() || e.rhs.has_yield(),
+            ASTKind::PrefixExpression(e) | ASTKind::SuffixExpression(e) => e.base.has_yield(),
+            ASTKind::StringExpression(e) => any_yield(&e.inner),
+            ASTKind::Template(e) => e.arguments.iter().any(|(_, v)| v.has_yield()) || any_yield(&e.children),
+            ASTKind::Dict(e) => e.values().any(|e| e.has_yield()),
+            ASTKind::Pair(k, v) => k.has_yield() || v.has_yield(),
+            _ => false,
+        }
+    }
+}
+
+impl CallApply {
+    fn has_yield(&self) -> bool {
+        any_yield(&self.arguments) || self.keywords.iter().any(|(_, v)| v.has_yield())
+    }
+}
+
+fn any_yield(nodes: &[ASTNode]) -> bool {
+    nodes.iter().any(|e| e.has_yield())
 }
@@ -0,0 +1,65 @@
+use super::*;
+use std::rc::Rc;
+
+///
+///
+/// ```sdl
+/// class $name {
+///     $field
+///     let $field = $default
+///     def $method(self) {
+///         $body
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ClassStatement {
+    pub name: ASTNode,
+    pub body: ASTNode,
+}
+
+/// A record type, calling it creates an [`Object`].
+#[derive(Clone)]
+pub struct Class {
+    pub name: String,
+    /// Fields in the order of declaration, with the default value if any.
+    pub fields: IndexMap<String, Option<ASTNode>>,
+    pub methods: IndexMap<String, ASTNode>,
+}
+
+/// An instance of a [`Class`].
+#[derive(Clone, Eq, PartialEq)]
+pub struct Object {
+    pub class: Rc<Class>,
+    pub fields: IndexMap<String, ASTNode>,
+}
+
+impl Debug for Class {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Class({})", self.name)
+    }
+}
+
+impl PartialEq for Class {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.fields == other.fields && self.methods == other.methods
+    }
+}
+
+impl Eq for Class {}
+
+impl Debug for Object {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut builder = f.debug_struct(&self.class.name);
+        for (k, v) in &self.fields {
+            builder.field(k, &v.kind);
+        }
+        builder.finish()
+    }
+}
+
+impl ClassStatement {
+    pub fn name(&self) -> String {
+        self.name.as_string()
+    }
+}
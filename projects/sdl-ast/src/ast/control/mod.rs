@@ -0,0 +1,55 @@
+use super::*;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ControlKind {
+    Return,
+    Break,
+    Pass,
+    Yield,
+}
+
+///
+///
+/// ```sdl
+/// return $value
+/// yield $value
+/// break
+/// pass
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ControlFlow {
+    pub kind: ControlKind,
+    pub value: Option<ASTNode>,
+}
+
+/// A `return` or `break` on its way out, enclosing blocks are unwound until a function or a loop takes it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Signal {
+    pub kind: ControlKind,
+    pub value: Option<ASTNode>,
+    /// Values produced by the unwound blocks before the signal was raised.
+    pub output: Vec<ASTNode>,
+    pub range: Range,
+}
+
+impl ControlKind {
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Return => "return",
+            Self::Break => "break",
+            Self::Pass => "pass",
+            Self::Yield => "yield",
+        }
+    }
+}
+
+impl From<&str> for ControlKind {
+    fn from(s: &str) -> Self {
+        match s {
+            "return" => Self::Return,
+            "break" => Self::Break,
+            "yield" => Self::Yield,
+            _ => Self::Pass,
+        }
+    }
+}
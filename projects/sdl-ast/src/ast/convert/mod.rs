@@ -1,4 +1,5 @@
 use super::*;
+use num::FromPrimitive;
 
 impl Default for ASTKind {
     fn default() -> Self {
@@ -44,3 +45,42 @@ impl From<bool> for ASTKind {
         Self::Boolean(v)
     }
 }
+
+impl From<i32> for ASTKind {
+    fn from(v: i32) -> Self {
+        Self::Integer(Box::new(BigInt::from(v)))
+    }
+}
+
+impl From<i64> for ASTKind {
+    fn from(v: i64) -> Self {
+        Self::Integer(Box::new(BigInt::from(v)))
+    }
+}
+
+impl From<usize> for ASTKind {
+    fn from(v: usize) -> Self {
+        Self::Integer(Box::new(BigInt::from(v)))
+    }
+}
+
+impl From<f64> for ASTKind {
+    fn from(v: f64) -> Self {
+        match BigDecimal::from_f64(v) {
+            Some(s) => Self::Decimal(Box::new(s)),
+            None => Self::Null,
+        }
+    }
+}
+
+impl From<Vec<ASTNode>> for ASTKind {
+    fn from(v: Vec<ASTNode>) -> Self {
+        Self::List(v)
+    }
+}
+
+impl From<IndexMap<String, ASTNode>> for ASTKind {
+    fn from(v: IndexMap<String, ASTNode>) -> Self {
+        Self::Dict(Box::new(v))
+    }
+}
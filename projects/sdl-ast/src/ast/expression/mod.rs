@@ -1,6 +1,7 @@
 use super::*;
 use std::ops::AddAssign;
 
+/// With a handler, `inner` alternates literal pieces and interpolations, starting and ending with a piece.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct StringExpression {
     pub handler: Option<ASTNode>,
@@ -26,6 +27,35 @@ pub struct CallChain {
     pub chain: Vec<ASTNode>,
 }
 
+/// eg: `f(a, b = 1)`
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CallApply {
+    pub arguments: Vec<ASTNode>,
+    pub keywords: Vec<(ASTNode, ASTNode)>,
+}
+
+/// eg: `a.f` or `a.f(b)`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CallDot {
+    pub symbol: ASTNode,
+    pub apply: Option<ASTNode>,
+}
+
+/// eg: `a["key"]`, `xs[1:3]`, `xs[::-1]` or `m[1, 2]`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CallSlice {
+    pub indexes: Vec<SliceIndex>,
+}
+
+/// One dimension of a [`CallSlice`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SliceIndex {
+    /// eg: `1`, `-1` or `"key"`
+    Index(ASTNode),
+    /// eg: `1:3`, `::2` or `3:1:-1`, bounds are inclusive
+    Range { start: Option<ASTNode>, end: Option<ASTNode>, step: Option<ASTNode> },
+}
+
 impl Default for CallChain {
     fn default() -> Self {
         Self { base: Default::default(), chain: vec![] }
@@ -0,0 +1,20 @@
+use super::*;
+
+///
+///
+/// ```sdl
+/// extend $target with {
+///     let $region = $value
+///     $children
+/// }
+/// ```
+///
+/// When `target` is a layout, each definition in the block overrides the region with the same name,
+/// and the other values in the block become its `children`.
+///
+/// When `target` is a class or a builtin type such as `String`, functions in the block become its methods.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExtendStatement {
+    pub target: ASTNode,
+    pub body: ASTNode,
+}
@@ -0,0 +1,124 @@
+use super::*;
+use crate::{Result, SDLContext};
+use std::rc::Rc;
+
+///
+///
+/// ```sdl
+/// def $name($a, $b = $default) {
+///     $body
+/// }
+///
+/// $name($a, $b = $default) = $body
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FunctionDefine {
+    pub name: ASTNode,
+    pub parameters: Vec<(ASTNode, Option<ASTNode>)>,
+    pub body: ASTNode,
+}
+
+/// A function value, which holds the scope where it was defined.
+#[derive(Clone)]
+pub struct Function {
+    pub define: Rc<FunctionDefine>,
+    pub env: SDLContext,
+}
+
+/// The result of calling a function which contains `yield`, its body runs when it is iterated by `for ... in`.
+#[derive(Clone)]
+pub struct Generator {
+    pub function: Function,
+    /// Scope of the call with arguments bound.
+    pub scope: SDLContext,
+}
+
+/// A function implemented by the host, see [`SDLContext::register_fn`].
+#[derive(Clone)]
+pub struct NativeFunction {
+    pub name: String,
+    pub function: Rc<dyn Fn(CallApply, Range) -> Result<ASTNode>>,
+    /// A value passed as the first argument of every call, eg: the function under `#deprecated`.
+    pub bound: Option<Box<ASTNode>>,
+}
+
+impl Debug for Function {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Function({})", self.name())
+    }
+}
+
+impl Debug for Generator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Generator({})", self.function.name())
+    }
+}
+
+impl PartialEq for Generator {
+    fn eq(&self, other: &Self) -> bool {
+        self.function == other.function
+    }
+}
+
+impl Eq for Generator {}
+
+impl PartialEq for Function {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.define, &other.define) || self.define == other.define
+    }
+}
+
+impl Eq for Function {}
+
+impl Debug for NativeFunction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "NativeFunction({})", self.name)
+    }
+}
+
+impl PartialEq for NativeFunction {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.function, &other.function) && self.bound == other.bound
+    }
+}
+
+impl Eq for NativeFunction {}
+
+impl FunctionDefine {
+    pub fn name(&self) -> String {
+        self.name.as_string()
+    }
+    pub fn parameter_names(&self) -> Vec<String> {
+        self.parameters.iter().map(|(name, _)| name.as_string()).collect()
+    }
+    /// A function whose own body contains `yield` is a generator.
+    pub fn is_generator(&self) -> bool {
+        self.body.has_yield()
+    }
+}
+
+impl Function {
+    pub fn new(define: FunctionDefine, env: SDLContext) -> Self {
+        Self { define: Rc::new(define), env }
+    }
+    pub fn name(&self) -> String {
+        self.define.name()
+    }
+}
+
+impl NativeFunction {
+    pub fn new(name: impl Into<String>, f: impl Fn(CallApply, Range) -> Result<ASTNode> + 'static) -> Self {
+        Self { name: name.into(), function: Rc::new(f), bound: None }
+    }
+    /// Pass `value` as the first argument of every call.
+    pub fn bind(self, value: ASTNode) -> Self {
+        Self { bound: Some(Box::new(value)), ..self }
+    }
+    pub fn call(&self, mut args: CallApply, position: Range) -> Result<ASTNode> {
+        if let Some(bound) = &self.bound {
+            args.arguments.insert(0, bound.as_ref().to_owned());
+        }
+        let out = (self.function)(args, position)?;
+        Ok(ASTNode { kind: out.kind, range: position })
+    }
+}
@@ -0,0 +1,105 @@
+use super::*;
+
+///
+///
+/// ```sdl
+/// import a::b as c
+/// import "partials/nav.sdl" as nav
+/// import ..a::{x, y as z}
+/// import a::*
+/// ```
+///
+/// Nested braces are flattened, so every [`ImportItem`] holds a full path.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ImportStatement {
+    pub items: Vec<ImportItem>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ImportItem {
+    pub path: ModulePath,
+    pub alias: Option<String>,
+    /// eg: `import a::*`
+    pub glob: bool,
+    pub range: Range,
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub enum ModulePath {
+    /// eg: `..a::b`, the number of leading dots and the segments
+    Symbol { relative: usize, segments: Vec<String> },
+    /// eg: `"partials/nav.sdl"`
+    File(String),
+}
+
+impl Debug for ModulePath {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Symbol { relative, segments } => write!(f, "{}{}", ".".repeat(*relative), segments.join("::")),
+            Self::File(s) => write!(f, "{:?}", s),
+        }
+    }
+}
+
+impl ImportItem {
+    /// The name this item is bound to.
+    pub fn name(&self) -> String {
+        if let Some(s) = &self.alias {
+            return s.to_owned();
+        }
+        match &self.path {
+            ModulePath::Symbol { segments, .. } => segments.last().cloned().unwrap_or_default(),
+            ModulePath::File(s) => s.rsplit('/').next().unwrap_or_default().trim_end_matches(".sdl").to_string(),
+        }
+    }
+}
+
+impl ModulePath {
+    /// Split `a::b` into the module `a` and the member `b`.
+    pub fn split_member(&self) -> Option<(ModulePath, String)> {
+        match self {
+            Self::Symbol { relative, segments } if segments.len() > 1 => {
+                let mut segments = segments.to_owned();
+                let member = segments.pop()?;
+                Some((Self::Symbol { relative: *relative, segments }, member))
+            }
+            _ => None,
+        }
+    }
+    /// The id of the module as a normalized `/` separated path from the root, eg: `a::b` is `a/b.sdl`.
+    ///
+    /// Relative paths, that is symbols with leading dots and files starting with `./` or `../`, are
+    /// resolved from the directory of `base`, which is the id of the importing module.
+    pub fn to_id(&self, base: Option<&str>) -> String {
+        let mut dir: Vec<&str> = match base {
+            Some(s) => s.split('/').collect(),
+            None => vec![],
+        };
+        // the file name of the importing module
+        dir.pop();
+        let path = match self {
+            Self::Symbol { relative: 0, segments } => return format!("{}.sdl", segments.join("/")),
+            Self::Symbol { relative, segments } => {
+                for _ in 1..*relative {
+                    dir.pop();
+                }
+                format!("{}.sdl", segments.join("/"))
+            }
+            Self::File(s) if s.starts_with("./") || s.starts_with("../") => s.to_owned(),
+            Self::File(s) => {
+                dir.clear();
+                s.to_owned()
+            }
+        };
+        for part in path.split('/') {
+            match part {
+                "" | "." => (),
+                ".." => {
+                    dir.pop();
+                }
+                _ => dir.push(part),
+            }
+        }
+        dir.join("/")
+    }
+}
@@ -24,6 +24,8 @@ use super::*;
 ///     }
 /// }
 /// ```
+///
+/// Inside the block, `loop` holds `index`, `index0`, `first`, `last` and `length` of the iteration.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ForInLoop {
     pub pattern: ASTNode,
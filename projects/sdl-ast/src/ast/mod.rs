@@ -1,4 +1,11 @@
+mod annotation;
+mod assign;
+mod class;
+mod control;
 mod expression;
+mod extend;
+mod function;
+mod import;
 mod loops;
 mod operations;
 mod symbol;
@@ -7,15 +14,26 @@ mod check;
 mod convert;
 
 pub use crate::ast::{
-    expression::{CallChain, InfixExpression, StringExpression, UnaryExpression},
+    annotation::{Annotation, AnnotationStatement},
+    assign::{Assign, AssignKind},
+    class::{Class, ClassStatement, Object},
+    control::{ControlFlow, ControlKind, Signal},
+    expression::{CallApply, CallChain, CallDot, CallSlice, InfixExpression, SliceIndex, StringExpression, UnaryExpression},
+    extend::ExtendStatement,
+    function::{Function, FunctionDefine, Generator, NativeFunction},
+    import::{ImportItem, ImportStatement, ModulePath},
     loops::{ForInLoop, IfElseChain},
     operations::Operator,
     symbol::Symbol,
-    template::{Template, TemplateKind},
+    template::{HTMLElement, Template, TemplateKind, VOID_TAGS},
 };
 use bigdecimal::BigDecimal;
+pub use indexmap::IndexMap;
 use num::BigInt;
-use std::fmt::{self, Debug, Display, Formatter};
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    rc::Rc,
+};
 pub use lsp_types::Range;
 pub use lsp_types::Position;
 
@@ -34,20 +52,34 @@ pub enum ASTKind {
 
     IfElseChain(Box<IfElseChain>),
     ForInLoop(Box<ForInLoop>),
+    ControlFlow(Box<ControlFlow>),
+    /// A `return` or `break` unwinding the evaluation
+    Signal(Box<Signal>),
+    Assign(Box<Assign>),
+    FunctionDefine(Box<FunctionDefine>),
+    ClassStatement(Box<ClassStatement>),
+    AnnotationStatement(Box<AnnotationStatement>),
+    ExtendStatement(Box<ExtendStatement>),
+    ImportStatement(Box<ImportStatement>),
 
     Expression(Box<ASTNode>, bool),
     CallChain(Box<CallChain>),
     CallIndex(Box<BigInt>),
+    CallApply(Box<CallApply>),
+    CallDot(Box<CallDot>),
+    CallSlice(Box<CallSlice>),
     InfixExpression(Box<InfixExpression>),
     PrefixExpression(Box<UnaryExpression>),
     SuffixExpression(Box<UnaryExpression>),
     StringExpression(Box<StringExpression>),
 
     Template(Box<Template>),
+    HTMLElement(Box<HTMLElement>),
 
     Text,
     List(Vec<ASTNode>),
-    Dict,
+    /// Insertion ordered
+    Dict(Box<IndexMap<String, ASTNode>>),
     Pair(Box<ASTNode>, Box<ASTNode>),
 
     Null,
@@ -60,6 +92,11 @@ pub enum ASTKind {
     Decimal(Box<BigDecimal>),
     Operator(Box<Operator>),
     Symbol(Box<Symbol>),
+    Function(Box<Function>),
+    NativeFunction(Box<NativeFunction>),
+    Generator(Box<Generator>),
+    Class(Rc<Class>),
+    Object(Box<Object>),
 }
 
 impl Debug for ASTNode {
@@ -111,6 +148,41 @@ impl ASTNode {
         Self { kind, range, }
     }
 
+    pub fn control_flow(kind: ControlKind, value: Option<ASTNode>, range: Range) -> Self {
+        let kind = ASTKind::ControlFlow(Box::new(ControlFlow { kind, value }));
+        Self { kind, range, }
+    }
+
+    pub fn assign(kind: AssignKind, pattern: ASTNode, value: ASTNode, range: Range) -> Self {
+        let kind = ASTKind::Assign(Box::new(Assign { kind, pattern, value }));
+        Self { kind, range, }
+    }
+
+    pub fn function_define(name: ASTNode, parameters: Vec<(ASTNode, Option<ASTNode>)>, body: ASTNode, range: Range) -> Self {
+        let kind = ASTKind::FunctionDefine(Box::new(FunctionDefine { name, parameters, body }));
+        Self { kind, range, }
+    }
+
+    pub fn class_statement(name: ASTNode, body: ASTNode, range: Range) -> Self {
+        let kind = ASTKind::ClassStatement(Box::new(ClassStatement { name, body }));
+        Self { kind, range, }
+    }
+
+    pub fn annotation_statement(annotations: Vec<Annotation>, statement: ASTNode, range: Range) -> Self {
+        let kind = ASTKind::AnnotationStatement(Box::new(AnnotationStatement { annotations, statement }));
+        Self { kind, range, }
+    }
+
+    pub fn extend_statement(target: ASTNode, body: ASTNode, range: Range) -> Self {
+        let kind = ASTKind::ExtendStatement(Box::new(ExtendStatement { target, body }));
+        Self { kind, range, }
+    }
+
+    pub fn import_statement(items: Vec<ImportItem>, range: Range) -> Self {
+        let kind = ASTKind::ImportStatement(Box::new(ImportStatement { items }));
+        Self { kind, range, }
+    }
+
     pub fn expression(children: ASTNode, eos: bool, range: Range) -> Self {
         let kind = ASTKind::Expression(Box::new(children), eos);
         Self { kind, range, }
@@ -142,7 +214,7 @@ impl ASTNode {
     }
 
     pub fn suffix_expression(op: ASTNode, lhs: ASTNode, range: Range) -> Self {
-        let kind = ASTKind::PrefixExpression(Box::new(UnaryExpression { op, base: lhs }));
+        let kind = ASTKind::SuffixExpression(Box::new(UnaryExpression { op, base: lhs }));
         Self { kind, range, }
     }
 
@@ -159,6 +231,21 @@ impl ASTNode {
         Self { kind, range, }
     }
 
+    pub fn call_apply(arguments: Vec<ASTNode>, keywords: Vec<(ASTNode, ASTNode)>, range: Range) -> Self {
+        let kind = ASTKind::CallApply(Box::new(CallApply { arguments, keywords }));
+        Self { kind, range, }
+    }
+
+    pub fn call_dot(symbol: ASTNode, apply: Option<ASTNode>, range: Range) -> Self {
+        let kind = ASTKind::CallDot(Box::new(CallDot { symbol, apply }));
+        Self { kind, range, }
+    }
+
+    pub fn call_slice(indexes: Vec<SliceIndex>, range: Range) -> Self {
+        let kind = ASTKind::CallSlice(Box::new(CallSlice { indexes }));
+        Self { kind, range, }
+    }
+
     pub fn template(value: Template, range: Range) -> Self {
         Self { kind: ASTKind::Template(Box::new(value)), range, }
     }
@@ -167,6 +254,10 @@ impl ASTNode {
         Self { kind: ASTKind::List(value), range, }
     }
 
+    pub fn dict(value: IndexMap<String, ASTNode>, range: Range) -> Self {
+        Self { kind: ASTKind::Dict(Box::new(value)), range, }
+    }
+
     pub fn null(range: Range) -> Self {
         Self { kind: ASTKind::Null, range, }
     }
@@ -0,0 +1,69 @@
+use crate::{utils::get_variant_name, ASTKind, ASTNode, Result, SDLError};
+use bigdecimal::BigDecimal;
+use lsp_types::Range;
+use std::{cmp::Ordering, rc::Rc};
+
+impl ASTKind {
+    /// Structural equality, numbers are compared by value so `1 == 1.0`.
+    ///
+    /// Objects are equal if they are instances of the same class with equal fields, the class is compared by
+    /// definition when it is not the same `Rc`, since reading a class from a scope makes a copy of it.
+    pub fn equals(&self, rhs: &ASTKind) -> bool {
+        match (self, rhs) {
+            (ASTKind::Integer(lhs), ASTKind::Decimal(rhs)) | (ASTKind::Decimal(rhs), ASTKind::Integer(lhs)) => {
+                &BigDecimal::from(lhs.as_ref().clone()) == rhs.as_ref()
+            }
+            (ASTKind::String(lhs), ASTKind::String(rhs))
+            | (ASTKind::String(lhs), ASTKind::HTMLText(rhs))
+            | (ASTKind::HTMLText(lhs), ASTKind::String(rhs))
+            | (ASTKind::HTMLText(lhs), ASTKind::HTMLText(rhs)) => lhs == rhs,
+            (ASTKind::List(lhs), ASTKind::List(rhs)) => {
+                lhs.len() == rhs.len() && lhs.iter().zip(rhs.iter()).all(|(l, r)| l.kind.equals(&r.kind))
+            }
+            (ASTKind::Dict(lhs), ASTKind::Dict(rhs)) => {
+                lhs.len() == rhs.len() && lhs.iter().all(|(k, l)| rhs.get(k).is_some_and(|r| l.kind.equals(&r.kind)))
+            }
+            (ASTKind::Object(lhs), ASTKind::Object(rhs)) => {
+                (Rc::ptr_eq(&lhs.class, &rhs.class) || lhs.class == rhs.class)
+                    && lhs.fields.len() == rhs.fields.len()
+                    && lhs.fields.iter().all(|(k, l)| rhs.fields.get(k).is_some_and(|r| l.kind.equals(&r.kind)))
+            }
+            _ => self == rhs,
+        }
+    }
+    /// Ordering between numbers or between strings, `None` if they are not comparable.
+    pub fn partial_compare(&self, rhs: &ASTKind) -> Option<Ordering> {
+        match (self, rhs) {
+            (ASTKind::Integer(lhs), ASTKind::Integer(rhs)) => lhs.partial_cmp(rhs),
+            (ASTKind::Decimal(lhs), ASTKind::Decimal(rhs)) => lhs.partial_cmp(rhs),
+            (ASTKind::Integer(lhs), ASTKind::Decimal(rhs)) => BigDecimal::from(lhs.as_ref().clone()).partial_cmp(rhs.as_ref()),
+            (ASTKind::Decimal(lhs), ASTKind::Integer(rhs)) => lhs.as_ref().partial_cmp(&BigDecimal::from(rhs.as_ref().clone())),
+            (ASTKind::String(lhs), ASTKind::String(rhs)) => lhs.partial_cmp(rhs),
+            _ => None,
+        }
+    }
+}
+
+impl ASTNode {
+    pub fn compare(&self, rhs: &ASTNode, position: Range) -> Result<Ordering> {
+        match self.kind.partial_compare(&rhs.kind) {
+            Some(o) => Ok(o),
+            None => Err(SDLError::invalid_operation(
+                format!("Cannot compare `{}` with `{}`", get_variant_name(&self.kind), get_variant_name(&rhs.kind)),
+                position,
+            )),
+        }
+    }
+    /// `item in self`, lists test their elements, strings test substrings and dicts test keys.
+    pub fn contains(&self, item: &ASTNode, position: Range) -> Result<bool> {
+        match (&self.kind, &item.kind) {
+            (ASTKind::List(list), _) => Ok(list.iter().any(|i| i.kind.equals(&item.kind))),
+            (ASTKind::String(s), ASTKind::String(sub)) => Ok(s.contains(sub.as_str())),
+            (ASTKind::Dict(dict), key) if key.as_key().is_some() => Ok(key.as_key().is_some_and(|k| dict.contains_key(&k))),
+            _ => Err(SDLError::invalid_operation(
+                format!("Cannot test `{}` in `{}`", get_variant_name(&item.kind), get_variant_name(&self.kind)),
+                position,
+            )),
+        }
+    }
+}
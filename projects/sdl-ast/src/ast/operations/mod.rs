@@ -1,4 +1,7 @@
+mod compare;
 mod native;
+mod number;
+mod slice;
 use super::*;
 
 #[derive(Clone, Eq, PartialEq)]
@@ -1,17 +1,16 @@
-use crate::utils::get_variant_name;
+use crate::utils::{escape_text, get_variant_name};
 use num::{ToPrimitive, Zero, BigInt};
-use std::ops::{Add, Div, Mul, Sub, Neg};
+use std::ops::{Add, Neg};
 use crate::{ASTNode, ASTKind, SDLError};
 use crate::Result;
-use crate::traits::Concat;
+use super::number::invalid_operands;
 use bigdecimal::BigDecimal;
 use lsp_types::Range;
 
-impl Add<ASTNode> for ASTNode {
-    type Output = Result<ASTNode>;
-
-    fn add(self, rhs: ASTNode) -> Self::Output {
-        let error = format!("(ASTKind::{}(lhs), ASTKind::{}(rhs)) => {{}}", get_variant_name(&self.kind), get_variant_name(&rhs.kind));
+impl ASTNode {
+    /// `+`, numbers are promoted to `Decimal` if either side is one.
+    pub fn add(self, rhs: ASTNode, position: Range) -> Result<ASTNode> {
+        let error = invalid_operands("+", &self, &rhs, position);
         let out = match (self.kind, rhs.kind) {
             (ASTKind::String(lhs), ASTKind::String(rhs)) => ASTKind::String(lhs + rhs.as_ref()),
             (ASTKind::Integer(lhs), ASTKind::Integer(rhs)) => ASTKind::Integer(Box::new(lhs.as_ref() + rhs.as_ref())),
@@ -19,10 +18,7 @@ impl Add<ASTNode> for ASTNode {
             (ASTKind::Decimal(lhs), ASTKind::Integer(rhs)) | (ASTKind::Integer(rhs), ASTKind::Decimal(lhs)) => {
                 ASTKind::Decimal(Box::new(lhs.as_ref() + BigDecimal::from(rhs.as_ref().clone())))
             }
-            _ => {
-                println!("{}", error);
-                unreachable!()
-            }
+            _ => return Err(error),
         };
         Ok(ASTNode {
             kind: out,
@@ -31,18 +27,16 @@ impl Add<ASTNode> for ASTNode {
     }
 }
 
-impl Sub<ASTNode> for ASTNode {
-    type Output = Result<ASTNode>;
-
-    fn sub(self, rhs: ASTNode) -> Self::Output {
-        let error = format!("(ASTKind::{}(lhs), ASTKind::{}(rhs)) => {{}}", get_variant_name(&self.kind), get_variant_name(&rhs.kind));
+impl ASTNode {
+    /// `-`
+    pub fn subtract(self, rhs: ASTNode, position: Range) -> Result<ASTNode> {
+        let error = invalid_operands("-", &self, &rhs, position);
         let out = match (self.kind, rhs.kind) {
             (ASTKind::Integer(lhs), ASTKind::Integer(rhs)) => ASTKind::Integer(Box::new(lhs.as_ref() - rhs.as_ref())),
             (ASTKind::Decimal(lhs), ASTKind::Decimal(rhs)) => ASTKind::Decimal(Box::new(lhs.as_ref() - rhs.as_ref())),
-            (ASTKind::Decimal(lhs), ASTKind::Integer(rhs)) | (ASTKind::Integer(rhs), ASTKind::Decimal(lhs)) => {
-                ASTKind::Decimal(Box::new(lhs.as_ref() - BigDecimal::from(rhs.as_ref().clone())))
-            }
-            _ => unimplemented!("{}", error),
+            (ASTKind::Decimal(lhs), ASTKind::Integer(rhs)) => ASTKind::Decimal(Box::new(lhs.as_ref() - BigDecimal::from(rhs.as_ref().clone()))),
+            (ASTKind::Integer(lhs), ASTKind::Decimal(rhs)) => ASTKind::Decimal(Box::new(BigDecimal::from(lhs.as_ref().clone()) - rhs.as_ref())),
+            _ => return Err(error),
         };
         Ok(ASTNode {
             kind: out,
@@ -51,32 +45,16 @@ impl Sub<ASTNode> for ASTNode {
     }
 }
 
-impl Mul<ASTNode> for ASTNode {
-    type Output = Result<ASTNode>;
-
-    fn mul(self, rhs: ASTNode) -> Self::Output {
-        let error = format!("(ASTKind::{}(lhs), ASTKind::{}(rhs)) => {{}}", get_variant_name(&self.kind), get_variant_name(&rhs.kind));
+impl ASTNode {
+    /// `*`
+    pub fn multiply(self, rhs: ASTNode, position: Range) -> Result<ASTNode> {
+        let error = invalid_operands("*", &self, &rhs, position);
         let out = match (self.kind, rhs.kind) {
             (ASTKind::Integer(lhs), ASTKind::Integer(rhs)) => ASTKind::Integer(Box::new(lhs.as_ref() * rhs.as_ref())),
             (ASTKind::Decimal(lhs), ASTKind::Decimal(rhs)) => ASTKind::Decimal(Box::new(lhs.as_ref() * rhs.as_ref())),
-            _ => unimplemented!("{}", error),
-        };
-        Ok(ASTNode {
-            kind: out,
-            range: self.range
-        })
-    }
-}
-
-impl Div<ASTNode> for ASTNode {
-    type Output = Result<ASTNode>;
-
-    fn div(self, rhs: ASTNode) -> Self::Output {
-        let error = format!("(ASTKind::{}(lhs), ASTKind::{}(rhs)) => {{}}", get_variant_name(&self.kind), get_variant_name(&rhs.kind));
-        let out = match (self.kind, rhs.kind) {
-            (ASTKind::Integer(lhs), ASTKind::Integer(rhs)) => ASTKind::Integer(Box::new(lhs.as_ref() / rhs.as_ref())),
-            (ASTKind::Decimal(lhs), ASTKind::Decimal(rhs)) => ASTKind::Decimal(Box::new(lhs.as_ref() / rhs.as_ref())),
-            _ => unimplemented!("{}", error),
+            (ASTKind::Decimal(lhs), ASTKind::Integer(rhs)) => ASTKind::Decimal(Box::new(lhs.as_ref() * BigDecimal::from(rhs.as_ref().clone()))),
+            (ASTKind::Integer(lhs), ASTKind::Decimal(rhs)) => ASTKind::Decimal(Box::new(BigDecimal::from(lhs.as_ref().clone()) * rhs.as_ref())),
+            _ => return Err(error),
         };
         Ok(ASTNode {
             kind: out,
@@ -85,14 +63,13 @@ impl Div<ASTNode> for ASTNode {
     }
 }
 
-impl Concat<ASTNode> for ASTNode {
-    type Output = Result<ASTNode>;
-
-    fn concat(self, rhs: ASTNode) -> Self::Output {
-        let error = format!("(ASTKind::{}(lhs), ASTKind::{}(rhs)) => {{}}", get_variant_name(&self.kind), get_variant_name(&rhs.kind));
+impl ASTNode {
+    /// `++`, joins strings, lists and dicts, a value next to a list is added to it.
+    pub fn concat(self, rhs: ASTNode, position: Range) -> Result<ASTNode> {
+        let error = invalid_operands("++", &self, &rhs, position);
         let out = match (&self.kind, rhs.kind) {
             (ASTKind::Integer(lhs), ASTKind::Integer(rhs)) => {
-              let new = lhs.mul(rhs.to_string().len()) + rhs.as_ref();
+              let new = lhs.as_ref() * rhs.to_string().len() + rhs.as_ref();
                 ASTKind::Integer(Box::new(new))
             },
             (ASTKind::Integer(lhs), ASTKind::String(rhs)) => {
@@ -101,15 +78,40 @@ impl Concat<ASTNode> for ASTNode {
             (ASTKind::String(lhs), ASTKind::String(rhs)) => {
                 ASTKind::String(lhs.to_string() + rhs.as_ref())
             }
+            (ASTKind::HTMLText(lhs), ASTKind::HTMLText(rhs)) => {
+                ASTKind::HTMLText(lhs.to_string() + rhs.as_ref())
+            }
+            (ASTKind::HTMLText(lhs), ASTKind::String(rhs)) => {
+                ASTKind::HTMLText(lhs.to_string() + escape_text(&rhs).as_ref())
+            }
+            (ASTKind::String(lhs), ASTKind::HTMLText(rhs)) => {
+                ASTKind::HTMLText(escape_text(lhs) + rhs.as_ref())
+            }
             (ASTKind::String(lhs), ASTKind::Integer(rhs)) => {
                 ASTKind::String(lhs.to_string() + rhs.to_string().as_ref())
             }
+            (ASTKind::List(lhs), ASTKind::List(rhs)) => {
+                let mut new = lhs.to_owned();
+                new.extend(rhs);
+                ASTKind::List(new)
+            }
+            (ASTKind::List(lhs), rhs) => {
+                let mut new = lhs.to_owned();
+                new.push(ASTNode { kind: rhs, range: Default::default() });
+                ASTKind::List(new)
+            }
             (_, ASTKind::List(rhs)) => {
                 let mut new = vec![self.to_owned()];
                 new.extend(rhs);
                 ASTKind::List(new)
             }
-            _ => unimplemented!("{}", error),
+            // keys of rhs overwrite those of lhs
+            (ASTKind::Dict(lhs), ASTKind::Dict(rhs)) => {
+                let mut new = lhs.as_ref().to_owned();
+                new.extend(*rhs);
+                ASTKind::Dict(Box::new(new))
+            }
+            _ => return Err(error),
         };
         Ok(ASTNode {
             kind: out,
@@ -118,7 +120,26 @@ impl Concat<ASTNode> for ASTNode {
     }
 }
 
+impl ASTKind {
+    /// Key of a dict, integer keys are stored as their decimal form, so `{1: "a"}[1]` is `{"1": "a"}["1"]`.
+    pub fn as_key(&self) -> Option<String> {
+        match self {
+            ASTKind::String(s) => Some(s.to_owned()),
+            ASTKind::Integer(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+}
+
 impl ASTNode {
+    /// Value of `key` if `self` is a dict containing it.
+    pub fn get_key(&self, key: &str) -> Option<ASTNode> {
+        match &self.kind {
+            ASTKind::Dict(dict) => dict.get(key).cloned(),
+            ASTKind::Object(object) => object.fields.get(key).cloned(),
+            _ => None,
+        }
+    }
     pub fn get_index(&self, n: &BigInt, p: Range) -> Result<ASTNode> {
         match n {
             n if n > &BigInt::zero()  => {
@@ -168,7 +189,7 @@ impl ASTNode {
                         }
                     },
                     ASTKind::String(string) => {
-                        let l = match string.len().checked_sub(n) {
+                        let l = match string.chars().count().checked_sub(n) {
                             Some(u) => {u},
                             None => {return Ok(ASTNode {
                                 kind: ASTKind::Null,
@@ -199,9 +220,14 @@ impl ASTNode {
             }
         }
     }
+    /// Name of the class for objects, otherwise name of the builtin type.
     pub fn get_type(&self) -> ASTNode {
+        let name = match &self.kind {
+            ASTKind::Object(o) => o.class.name.to_owned(),
+            _ => get_variant_name(&self.kind),
+        };
         ASTNode {
-            kind: ASTKind::String(get_variant_name(&self.kind)),
+            kind: ASTKind::String(name),
             range: self.range
         }
     }
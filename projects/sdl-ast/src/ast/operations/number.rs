@@ -0,0 +1,181 @@
+//! The numeric tower, `Integer` is promoted to `Decimal` whenever the exact result is not an integer.
+use crate::{utils::get_variant_name, ASTKind, ASTNode, Result, SDLError};
+use bigdecimal::{BigDecimal, FromPrimitive, ToPrimitive};
+use lsp_types::Range;
+use num::{BigInt, Integer, One, Signed, Zero};
+
+/// Powers with more digits than this, before or after the decimal point, are errors.
+const MAX_POWER_DIGITS: f64 = 20000.0;
+/// Extra digits kept by intermediate results of a decimal power.
+const GUARD_DIGITS: u64 = 10;
+
+impl ASTNode {
+    /// `/`, exact for integers that divide evenly, otherwise a `Decimal` rounded to `precision` digits.
+    pub fn divide(self, rhs: ASTNode, precision: u64, position: Range) -> Result<ASTNode> {
+        if rhs.kind.is_zero() {
+            return Err(SDLError::divide_by_zero(position));
+        }
+        let kind = match (&self.kind, &rhs.kind) {
+            (ASTKind::Integer(lhs), ASTKind::Integer(rhs)) => match lhs.div_rem(rhs) {
+                (q, r) if r.is_zero() => ASTKind::Integer(Box::new(q)),
+                _ => ASTKind::Decimal(Box::new(limit(to_decimal(lhs) / to_decimal(rhs), precision))),
+            },
+            _ => {
+                let (lhs, rhs) = self.as_decimal_pair(&rhs, "/", position)?;
+                ASTKind::Decimal(Box::new(limit(lhs / rhs, precision)))
+            }
+        };
+        Ok(ASTNode { kind, range: self.range })
+    }
+    /// `%`, the result has the same sign as the divisor.
+    pub fn modulo(self, rhs: ASTNode, position: Range) -> Result<ASTNode> {
+        if rhs.kind.is_zero() {
+            return Err(SDLError::divide_by_zero(position));
+        }
+        let kind = match (&self.kind, &rhs.kind) {
+            (ASTKind::Integer(lhs), ASTKind::Integer(rhs)) => ASTKind::Integer(Box::new(lhs.mod_floor(rhs))),
+            _ => {
+                let (lhs, rhs) = self.as_decimal_pair(&rhs, "%", position)?;
+                let mut out = &lhs % &rhs;
+                if !out.is_zero() && out.is_negative() != rhs.is_negative() {
+                    out += rhs;
+                }
+                ASTKind::Decimal(Box::new(out))
+            }
+        };
+        Ok(ASTNode { kind, range: self.range })
+    }
+    /// `^`, integer exponents are exact, fractional exponents go through `f64`.
+    pub fn power(self, rhs: ASTNode, precision: u64, position: Range) -> Result<ASTNode> {
+        let exponent = match &rhs.kind {
+            ASTKind::Integer(n) => Some(n.as_ref().clone()),
+            ASTKind::Decimal(n) if n.is_integer() => Some(n.with_scale(0).into_bigint_and_exponent().0),
+            ASTKind::Decimal(_) => None,
+            _ => return Err(invalid_operands("^", &self, &rhs, position)),
+        };
+        let exponent = match exponent {
+            Some(n) => n,
+            None => {
+                let (base, exponent) = self.as_decimal_pair(&rhs, "^", position)?;
+                if base.is_negative() {
+                    let info = "Cannot raise a negative number to a fractional power";
+                    return Err(SDLError::invalid_operation(info, position));
+                }
+                let out = base.to_f64().zip(exponent.to_f64()).and_then(|(b, e)| BigDecimal::from_f64(b.powf(e)));
+                return match out {
+                    Some(v) => Ok(ASTNode { kind: ASTKind::Decimal(Box::new(limit(v, precision).normalized())), range: self.range }),
+                    None => Err(SDLError::invalid_operation("Power is out of range", position)),
+                };
+            }
+        };
+        let n = match exponent.abs().to_usize() {
+            Some(n) => n,
+            None => return Err(SDLError::invalid_operation("Exponent is too large", position)),
+        };
+        // `0`, `1` and `-1` stay small for any exponent, otherwise estimate the number of digits of the result
+        let digits = self.kind.as_decimal().filter(|b| !b.is_zero()).map(|b| log10_abs(&b) * n as f64);
+        if n > 1 && digits.is_some_and(|d| d.abs() > MAX_POWER_DIGITS) {
+            return Err(SDLError::invalid_operation("Power is out of range", position));
+        }
+        let kind = match (&self.kind, exponent.is_negative()) {
+            (ASTKind::Integer(base), false) => ASTKind::Integer(Box::new(num::pow(base.as_ref().clone(), n))),
+            (ASTKind::Integer(base), true) => {
+                if base.is_zero() {
+                    return Err(SDLError::divide_by_zero(position));
+                }
+                let out = BigDecimal::one() / to_decimal(&num::pow(base.as_ref().clone(), n));
+                ASTKind::Decimal(Box::new(limit(out, precision)))
+            }
+            (ASTKind::Decimal(base), false) => ASTKind::Decimal(Box::new(limit(decimal_pow(base, n, precision), precision))),
+            (ASTKind::Decimal(base), true) => {
+                if base.is_zero() {
+                    return Err(SDLError::divide_by_zero(position));
+                }
+                let out = BigDecimal::one() / decimal_pow(base, n, precision);
+                ASTKind::Decimal(Box::new(limit(out, precision)))
+            }
+            _ => return Err(invalid_operands("^", &self, &rhs, position)),
+        };
+        Ok(ASTNode { kind, range: self.range })
+    }
+    /// `√x`, exact for perfect squares.
+    pub fn square_root(self, precision: u64, position: Range) -> Result<ASTNode> {
+        let value = match &self.kind {
+            ASTKind::Integer(v) => to_decimal(v),
+            ASTKind::Decimal(v) => v.as_ref().clone(),
+            _ => {
+                let info = format!("Cannot apply prefix `√` to type `{}`", get_variant_name(&self.kind));
+                return Err(SDLError::invalid_operation(info, position));
+            }
+        };
+        let root = match value.sqrt() {
+            Some(v) => limit(v, precision).normalized(),
+            None => return Err(SDLError::invalid_operation("Cannot take the square root of a negative number", position)),
+        };
+        let kind = match &self.kind {
+            ASTKind::Integer(v) => {
+                let int = v.sqrt();
+                match &int * &int == **v {
+                    true => ASTKind::Integer(Box::new(int)),
+                    false => ASTKind::Decimal(Box::new(root)),
+                }
+            }
+            _ => ASTKind::Decimal(Box::new(root)),
+        };
+        Ok(ASTNode { kind, range: self.range })
+    }
+    /// Promote both numbers to `Decimal`.
+    fn as_decimal_pair(&self, rhs: &ASTNode, op: &str, position: Range) -> Result<(BigDecimal, BigDecimal)> {
+        match (self.kind.as_decimal(), rhs.kind.as_decimal()) {
+            (Some(lhs), Some(rhs)) => Ok((lhs, rhs)),
+            _ => Err(invalid_operands(op, self, rhs, position)),
+        }
+    }
+}
+
+impl ASTKind {
+    pub fn as_decimal(&self) -> Option<BigDecimal> {
+        match self {
+            ASTKind::Integer(v) => Some(to_decimal(v)),
+            ASTKind::Decimal(v) => Some(v.as_ref().clone()),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn invalid_operands(op: &str, lhs: &ASTNode, rhs: &ASTNode, position: Range) -> SDLError {
+    let info = format!("Cannot apply `{}` to `{}` and `{}`", op, get_variant_name(&lhs.kind), get_variant_name(&rhs.kind));
+    SDLError::invalid_operation(info, position)
+}
+
+fn to_decimal(v: &BigInt) -> BigDecimal {
+    BigDecimal::from(v.clone())
+}
+
+fn log10_abs(v: &BigDecimal) -> f64 {
+    v.abs().to_f64().map_or(f64::INFINITY, f64::log10)
+}
+
+/// `base ^ n` by squaring, intermediate results are rounded and normalized so their size does not grow with `n`.
+fn decimal_pow(base: &BigDecimal, mut n: usize, precision: u64) -> BigDecimal {
+    let precision = precision + GUARD_DIGITS;
+    let mut out = BigDecimal::one();
+    let mut base = base.to_owned();
+    while n > 0 {
+        if n & 1 == 1 {
+            out = limit(out * &base, precision).normalized();
+        }
+        n >>= 1;
+        if n > 0 {
+            base = limit(&base * &base, precision).normalized();
+        }
+    }
+    out
+}
+
+fn limit(v: BigDecimal, precision: u64) -> BigDecimal {
+    match v.digits() > precision {
+        true => v.with_prec(precision),
+        false => v,
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{ast::SliceIndex, utils::get_variant_name, ASTKind, ASTNode, Result, SDLError};
+use lsp_types::Range;
+use num::{BigInt, Signed, ToPrimitive, Zero};
+
+impl ASTNode {
+    /// `self[a, b, ...]`, the indexes after a range apply to every item of the range, so `m[:, 1]` is the first column.
+    pub fn get_slice(&self, indexes: &[SliceIndex], p: Range) -> Result<ASTNode> {
+        let (head, rest) = match indexes.split_first() {
+            Some(s) => s,
+            None => return Ok(self.to_owned()),
+        };
+        let (start, end, step) = match head {
+            SliceIndex::Index(i) => return self.get_item(i, p)?.get_slice(rest, p),
+            SliceIndex::Range { start, end, step } => (start.as_ref(), end.as_ref(), step.as_ref()),
+        };
+        let sliced = self.get_range(start, end, step, p)?;
+        if rest.is_empty() {
+            return Ok(sliced);
+        }
+        match sliced.kind {
+            ASTKind::List(v) => {
+                let list = v.iter().map(|e| e.get_slice(rest, p)).collect::<Result<Vec<_>>>()?;
+                Ok(ASTNode { kind: ASTKind::List(list), range: self.range })
+            }
+            _ => Err(SDLError::invalid_index(slice_repr(rest), get_variant_name(&sliced.kind), p)),
+        }
+    }
+    /// `self[index]`, unlike `self.1` an index out of range is an error, a missing key of a dict is `null`.
+    pub fn get_item(&self, index: &ASTNode, p: Range) -> Result<ASTNode> {
+        let kind = match (&self.kind, &index.kind) {
+            (ASTKind::Dict(_), key) if key.as_key().is_some() => {
+                return Ok(key.as_key().and_then(|k| self.get_key(&k)).unwrap_or_else(|| ASTNode::null(p)));
+            }
+            (ASTKind::List(v), ASTKind::Integer(n)) => position(n, v.len()).map(|i| v[i].kind.to_owned()),
+            (ASTKind::String(s), ASTKind::Integer(n)) => {
+                position(n, s.chars().count()).and_then(|i| s.chars().nth(i)).map(|c| ASTKind::String(c.to_string()))
+            }
+            _ => None,
+        };
+        match kind {
+            Some(kind) => Ok(ASTNode { kind, range: self.range }),
+            None => Err(SDLError::invalid_index(index_repr(index), get_variant_name(&self.kind), p)),
+        }
+    }
+    /// `self[start:end:step]` of a list or a string, bounds are inclusive and clamped to the length.
+    fn get_range(&self, start: Option<&ASTNode>, end: Option<&ASTNode>, step: Option<&ASTNode>, p: Range) -> Result<ASTNode> {
+        let error = || SDLError::invalid_index(range_repr(start, end, step), get_variant_name(&self.kind), p);
+        let len = match &self.kind {
+            ASTKind::List(v) => v.len(),
+            ASTKind::String(s) => s.chars().count(),
+            _ => return Err(error()),
+        } as i64;
+        // indexes start from 1, so 0 is never a valid bound or step
+        let bound = |n: Option<&ASTNode>| match n.map(|n| &n.kind) {
+            None => Ok(None),
+            Some(ASTKind::Integer(n)) if !n.is_zero() => Ok(Some(saturate(n))),
+            Some(_) => Err(error()),
+        };
+        let (start, end, step) = (bound(start)?, bound(end)?, bound(step)?.unwrap_or(1));
+        let to_offset = |n: i64| if n > 0 { n - 1 } else { len + n };
+        let mut offsets = vec![];
+        if step > 0 {
+            let mut i = start.map(to_offset).unwrap_or(0).max(0);
+            let end = end.map(to_offset).unwrap_or(len - 1).min(len - 1);
+            while i <= end {
+                offsets.push(i as usize);
+                i = i.saturating_add(step);
+            }
+        } else {
+            let mut i = start.map(to_offset).unwrap_or(len - 1).min(len - 1);
+            let end = end.map(to_offset).unwrap_or(0).max(0);
+            while i >= end {
+                offsets.push(i as usize);
+                i = i.saturating_add(step);
+            }
+        }
+        let kind = match &self.kind {
+            ASTKind::List(v) => ASTKind::List(offsets.into_iter().map(|i| v[i].to_owned()).collect()),
+            ASTKind::String(s) => {
+                let chars: Vec<char> = s.chars().collect();
+                ASTKind::String(offsets.into_iter().map(|i| chars[i]).collect())
+            }
+            _ => unreachable!(),
+        };
+        Ok(ASTNode { kind, range: self.range })
+    }
+}
+
+/// 0-based offset of a 1-based index, negative indexes count from the end.
+fn position(n: &BigInt, len: usize) -> Option<usize> {
+    let (n, len) = (saturate(n), len as i64);
+    let i = match n {
+        n if n > 0 => n - 1,
+        n if n < 0 => len + n,
+        _ => return None,
+    };
+    match i >= 0 && i < len {
+        true => Some(i as usize),
+        false => None,
+    }
+}
+
+/// Indexes beyond `i64` are out of range anyway.
+fn saturate(n: &BigInt) -> i64 {
+    match n.to_i64() {
+        Some(n) => n,
+        None if n.is_negative() => i64::MIN / 2,
+        None => i64::MAX / 2,
+    }
+}
+
+fn index_repr(index: &ASTNode) -> String {
+    match &index.kind {
+        ASTKind::Integer(n) => n.to_string(),
+        ASTKind::String(s) => format!("{:?}", s),
+        _ => get_variant_name(&index.kind),
+    }
+}
+
+fn range_repr(start: Option<&ASTNode>, end: Option<&ASTNode>, step: Option<&ASTNode>) -> String {
+    let repr = |n: Option<&ASTNode>| n.map(index_repr).unwrap_or_default();
+    match step {
+        Some(_) => format!("{}:{}:{}", repr(start), repr(end), repr(step)),
+        None => format!("{}:{}", repr(start), repr(end)),
+    }
+}
+
+fn slice_repr(indexes: &[SliceIndex]) -> String {
+    let items: Vec<String> = indexes
+        .iter()
+        .map(|i| match i {
+            SliceIndex::Index(i) => index_repr(i),
+            SliceIndex::Range { start, end, step } => range_repr(start.as_ref(), end.as_ref(), step.as_ref()),
+        })
+        .collect();
+    items.join(", ")
+}
@@ -42,6 +42,12 @@ impl Symbol {
             })
             .collect()
     }
+    pub fn range(&self) -> Range {
+        match (self.path.first(), self.path.last()) {
+            (Some(head), Some(last)) => Range { start: head.range.start, end: last.range.end },
+            _ => Default::default(),
+        }
+    }
     pub fn name(&self) -> String {
         match &self.path.last().unwrap().kind {
             ASTKind::HTMLText(s) => s.to_owned(),
@@ -0,0 +1,59 @@
+use super::*;
+use indexmap::IndexSet;
+
+/// Elements that never have children or a closing tag.
+#[rustfmt::skip]
+pub static VOID_TAGS: &[&str; 16] = &[
+    "img", "hr", "br", "input", "link", "meta", "area", "base", "col", "wbr",
+    "command", "embed", "keygen", "param", "source", "track"
+];
+
+/// An evaluated [`Template`], eg: `<a id="home" class="nav" href="/" hidden></a>`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HTMLElement {
+    pub is_void: bool,
+    pub tag: String,
+    pub id: Option<String>,
+    pub class: IndexSet<String>,
+    /// Boolean attributes, eg: `disabled`.
+    pub attributes: IndexSet<String>,
+    /// Attributes with a value, in the order of the source.
+    pub arguments: IndexMap<String, ASTNode>,
+    pub children: Vec<ASTNode>,
+}
+
+impl HTMLElement {
+    pub fn new(tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        Self {
+            is_void: VOID_TAGS.contains(&tag.as_str()),
+            tag,
+            id: None,
+            class: Default::default(),
+            attributes: Default::default(),
+            arguments: Default::default(),
+            children: vec![],
+        }
+    }
+    /// A fragment has no tag, only its children are rendered.
+    pub fn fragment() -> Self {
+        Self::new("")
+    }
+    pub fn is_fragment(&self) -> bool {
+        self.tag.is_empty()
+    }
+    pub fn add_class(&mut self, class: &str) {
+        for c in class.split_whitespace() {
+            self.class.insert(c.to_string());
+        }
+    }
+    /// Append an evaluated child, blocks and fragments are flattened and `null`s are dropped.
+    pub fn add_child(&mut self, child: ASTNode) {
+        match child.kind {
+            ASTKind::Null => (),
+            ASTKind::Block(v) => v.into_iter().for_each(|e| self.add_child(e)),
+            ASTKind::HTMLElement(e) if e.is_fragment() => e.children.into_iter().for_each(|e| self.add_child(e)),
+            _ => self.children.push(child),
+        }
+    }
+}
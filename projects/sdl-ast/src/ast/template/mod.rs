@@ -1,4 +1,7 @@
+mod element;
+
 use super::*;
+pub use element::{HTMLElement, VOID_TAGS};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TemplateKind {
@@ -10,6 +13,12 @@ pub enum TemplateKind {
     HTMLBadTemplate,
     /// <\img> </img>
     SDLSpecialTemplate,
+    /// eg: <> </>
+    FragmentTemplate,
+    /// eg: <\> </>
+    SDLFragmentTemplate,
+    /// eg: <|>
+    EmptyTemplate,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -41,6 +50,15 @@ impl Template {
     pub fn sdl_special() -> Self {
         Self { kind: TemplateKind::SDLSpecialTemplate, ..Self::default() }
     }
+    pub fn fragment() -> Self {
+        Self { kind: TemplateKind::FragmentTemplate, ..Self::default() }
+    }
+    pub fn sdl_fragment() -> Self {
+        Self { kind: TemplateKind::SDLFragmentTemplate, ..Self::default() }
+    }
+    pub fn empty() -> Self {
+        Self { kind: TemplateKind::EmptyTemplate, ..Self::default() }
+    }
 }
 
 impl Template {
@@ -56,6 +74,9 @@ impl Template {
     pub fn set_arguments(&mut self, values: Vec<(ASTNode, ASTNode)>) {
         self.arguments = values
     }
+    pub fn set_children(&mut self, values: Vec<ASTNode>) {
+        self.children = values
+    }
 }
 
 /*
@@ -0,0 +1,90 @@
+use super::*;
+use crate::{
+    ast::{CallApply, NativeFunction},
+    utils::get_variant_name,
+    ASTKind,
+};
+
+/// The value under an annotation, eg: `#memo(60) def f() { ... }`.
+#[derive(Clone, Debug)]
+pub struct Annotated {
+    /// Name defined by the statement, `None` if the statement defines nothing.
+    pub name: Option<String>,
+    pub value: ASTNode,
+    /// Evaluated arguments of the annotation.
+    pub arguments: CallApply,
+    /// Range of the annotation.
+    pub position: Range,
+}
+
+/// Returns the value that replaces the annotated one.
+type AnnotationFn = dyn Fn(Annotated, &SDLContext) -> Result<ASTNode>;
+
+#[derive(Clone)]
+pub(crate) struct AnnotationHandler(Rc<AnnotationFn>);
+
+impl Debug for AnnotationHandler {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("AnnotationHandler")
+    }
+}
+
+impl AnnotationHandler {
+    pub fn call(&self, target: Annotated, ctx: &SDLContext) -> Result<ASTNode> {
+        (self.0)(target, ctx)
+    }
+}
+
+impl SDLContext {
+    /// Register an annotation implemented by the host, it shadows the builtin annotation with the same name and
+    /// can be used in every scope and imported module.
+    ///
+    /// ```
+    /// # use sdl_ast::SDLContext;
+    /// let mut ctx = SDLContext::default();
+    /// ctx.register_annotation("inline", |target, _| Ok(target.value));
+    /// ```
+    pub fn register_annotation(&mut self, name: &str, f: impl Fn(Annotated, &SDLContext) -> Result<ASTNode> + 'static) {
+        self.host.annotations.borrow_mut().insert(name.to_string(), AnnotationHandler(Rc::new(f)));
+    }
+    /// Search the annotation `name` in the annotations registered by the host, then in the builtins.
+    pub(crate) fn get_annotation(&self, name: &str) -> Option<AnnotationHandler> {
+        let found = self.host.annotations.borrow().get(name).cloned();
+        found.or_else(|| builtin_annotation(name))
+    }
+}
+
+fn builtin_annotation(name: &str) -> Option<AnnotationHandler> {
+    // `#test` is handled by the evaluator, see `SDLContextConfig::run_tests`
+    let f = match name {
+        "deprecated" => deprecated,
+        _ => return None,
+    };
+    Some(AnnotationHandler(Rc::new(f)))
+}
+
+/// `#deprecated("use `b` instead")` warns every time the function or class is called.
+fn deprecated(target: Annotated, ctx: &SDLContext) -> Result<ASTNode> {
+    let kind = match &target.value.kind {
+        ASTKind::Function(_) | ASTKind::NativeFunction(_) => "Function",
+        ASTKind::Class(_) => "Class",
+        _ => {
+            let msg = format!("Annotation `deprecated` expected `Function` or `Class`, found `{}`", get_variant_name(&target.value.kind));
+            return Err(SDLError::invalid_type(msg, target.position));
+        }
+    };
+    let name = target.name.unwrap_or_default();
+    let mut info = format!("{} `{}` is deprecated", kind, name);
+    if let Some(note) = target.arguments.arguments.first() {
+        info = format!("{}, {}", info, note.as_string());
+    }
+    // the wrapper is stored back in `ctx`, so only the sink is captured and the value is bound as an argument
+    let sink = ctx.warning_sink();
+    let f = NativeFunction::new(name, move |mut args: CallApply, position: Range| {
+        sink.warn(SDLError::deprecated(info.to_owned(), position))?;
+        let value = args.arguments.remove(0);
+        value.call(args, position)
+    });
+    let f = f.bind(target.value);
+    Ok(ASTNode { kind: ASTKind::NativeFunction(Box::new(f)), range: target.position })
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+/// What to do with warnings raised during evaluation, such as a non-exhaustive `if`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum WarningLevel {
+    /// Drop warnings silently.
+    Allow,
+    /// Collect warnings, see [`SDLContext::take_warnings`].
+    #[default]
+    Warn,
+    /// Fail the evaluation with the first warning.
+    Deny,
+}
+
+/// Where the warnings of a context go, values can hold it without holding the scopes of the context.
+#[derive(Clone)]
+pub(crate) struct WarningSink {
+    level: WarningLevel,
+    diagnostics: Rc<RefCell<Vec<SDLError>>>,
+}
+
+impl WarningSink {
+    pub fn warn(&self, warning: SDLError) -> Result<()> {
+        match self.level {
+            WarningLevel::Allow => Ok(()),
+            WarningLevel::Warn => {
+                self.diagnostics.borrow_mut().push(warning);
+                Ok(())
+            }
+            WarningLevel::Deny => Err(warning),
+        }
+    }
+}
+
+impl SDLContext {
+    /// Report a warning, which is handled by [`SDLContextConfig::warnings`].
+    pub fn warn(&self, warning: SDLError) -> Result<()> {
+        self.warning_sink().warn(warning)
+    }
+    /// Warnings reported later through the sink follow the config of this context at the time of the call.
+    pub(crate) fn warning_sink(&self) -> WarningSink {
+        WarningSink { level: self.config().warnings, diagnostics: self.diagnostics.clone() }
+    }
+    /// Remove and return the warnings collected so far, in the order they were raised.
+    pub fn take_warnings(&self) -> Vec<SDLError> {
+        self.diagnostics.take()
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+use crate::ast::IndexMap;
+
+type Dict = IndexMap<String, ASTNode>;
+
+pub(super) fn method(name: &str) -> Option<Method> {
+    let f: Method = match name {
+        "len" => len,
+        "is_empty" => is_empty,
+        "keys" => keys,
+        "values" => values,
+        "items" => items,
+        "contains" => contains,
+        "get" => get,
+        _ => return None,
+    };
+    Some(f)
+}
+
+fn len(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<Dict>().len())
+}
+
+fn is_empty(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<Dict>().is_empty())
+}
+
+fn keys(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    let out: Vec<ASTNode> = call.this::<Dict>().into_iter().map(|(k, _)| ASTNode::from(k)).collect();
+    call.output(out)
+}
+
+fn values(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    let out: Vec<ASTNode> = call.this::<Dict>().into_iter().map(|(_, v)| v).collect();
+    call.output(out)
+}
+
+/// `[[key, value], ...]` in insertion order.
+fn items(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    let out: Vec<ASTNode> = call.this::<Dict>().into_iter().map(|(k, v)| ASTNode::from(vec![ASTNode::from(k), v])).collect();
+    call.output(out)
+}
+
+fn contains(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 1)?;
+    let key = key(&call)?;
+    call.output(call.this::<Dict>().contains_key(&key))
+}
+
+/// Value of the key, or the second argument (`null` by default) if the key is missing.
+fn get(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 2)?;
+    let key = key(&call)?;
+    match call.this::<Dict>().get(&key) {
+        Some(v) => call.output(v.kind.to_owned()),
+        None => call.output(call.get::<ASTNode>(1)?.map(|v| v.kind).unwrap_or_default()),
+    }
+}
+
+/// The first argument as a key, see [`ASTKind::as_key`].
+fn key(call: &MethodCall) -> Result<String> {
+    let node = call.get::<ASTNode>(0)?.unwrap_or_default();
+    match node.kind.as_key() {
+        Some(key) => Ok(key),
+        None => {
+            let msg = format!("Method `{}` expected `String` for argument 1, found `{}`", call.name, get_variant_name(&node.kind));
+            Err(SDLError::invalid_type(msg, call.position))
+        }
+    }
+}
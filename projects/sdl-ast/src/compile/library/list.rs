@@ -0,0 +1,101 @@
+use super::*;
+
+pub(super) fn method(name: &str) -> Option<Method> {
+    let f: Method = match name {
+        "len" => len,
+        "is_empty" => is_empty,
+        "first" => first,
+        "last" => last,
+        "reverse" => reverse,
+        "contains" => contains,
+        "enumerate" => enumerate,
+        "map" => map,
+        "filter" => filter,
+        "join" => join,
+        _ => return None,
+    };
+    Some(f)
+}
+
+fn len(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<Vec<ASTNode>>().len())
+}
+
+fn is_empty(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<Vec<ASTNode>>().is_empty())
+}
+
+fn first(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<Vec<ASTNode>>().first().map(|e| e.kind.to_owned()).unwrap_or_default())
+}
+
+fn last(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<Vec<ASTNode>>().last().map(|e| e.kind.to_owned()).unwrap_or_default())
+}
+
+fn reverse(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    let mut list = call.this::<Vec<ASTNode>>();
+    list.reverse();
+    call.output(list)
+}
+
+fn contains(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 1)?;
+    let item = call.get::<ASTNode>(0)?.unwrap_or_default();
+    call.output(call.this::<Vec<ASTNode>>().iter().any(|e| e.kind.equals(&item.kind)))
+}
+
+/// `[a, b].enumerate()` gives `[[1, a], [2, b]]`, indexes start from 1 as `.1` does.
+fn enumerate(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    let list = call.this::<Vec<ASTNode>>().into_iter().enumerate();
+    call.output(list.map(|(i, e)| ASTNode::from(vec![ASTNode::from(i + 1), e])).collect::<Vec<_>>())
+}
+
+fn map(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 1)?;
+    let f = call.get::<ASTNode>(0)?.unwrap_or_default();
+    let mut out = vec![];
+    for e in call.this::<Vec<ASTNode>>() {
+        out.push(f.call(CallApply { arguments: vec![e], keywords: vec![] }, call.position)?)
+    }
+    call.output(out)
+}
+
+fn filter(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 1)?;
+    let f = call.get::<ASTNode>(0)?.unwrap_or_default();
+    let mut out = vec![];
+    for e in call.this::<Vec<ASTNode>>() {
+        let keep = f.call(CallApply { arguments: vec![e.to_owned()], keywords: vec![] }, call.position)?;
+        if keep.kind.is_truthy() {
+            out.push(e)
+        }
+    }
+    call.output(out)
+}
+
+fn join(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 1)?;
+    let separator = call.get::<String>(0)?.unwrap_or_default();
+    let mut out = vec![];
+    for e in call.this::<Vec<ASTNode>>() {
+        match &e.kind {
+            ASTKind::String(s) | ASTKind::HTMLText(s) => out.push(s.to_owned()),
+            ASTKind::Integer(n) => out.push(n.to_string()),
+            ASTKind::Decimal(n) => out.push(n.to_string()),
+            ASTKind::Boolean(b) => out.push(b.to_string()),
+            ASTKind::Null => out.push(String::from("null")),
+            _ => {
+                let msg = format!("Method `{}` can not join an item of type `{}`", call.name, get_variant_name(&e.kind));
+                return Err(SDLError::invalid_type(msg, call.position));
+            }
+        }
+    }
+    call.output(out.join(&separator))
+}
@@ -0,0 +1,94 @@
+mod dict;
+mod list;
+mod number;
+mod string;
+
+use crate::{
+    ast::{CallApply, Range},
+    utils::get_variant_name,
+    ASTKind, ASTNode, FromASTNode, Result, SDLError,
+};
+
+pub(crate) type Method = fn(MethodCall) -> Result<ASTNode>;
+
+/// A call of builtin method, eg: `[1, 2].join(", ")`.
+pub(crate) struct MethodCall {
+    pub name: String,
+    pub this: ASTNode,
+    pub arguments: Vec<ASTNode>,
+    pub position: Range,
+}
+
+/// Whether values of the builtin type `type_name` have the builtin method `name`.
+pub(crate) fn has_builtin_method(type_name: &str, name: &str) -> bool {
+    let found = match type_name {
+        "List" => list::method(name),
+        "Dict" => dict::method(name),
+        "String" | "HTMLText" => string::method(name),
+        "Integer" => number::integer_method(name),
+        "Decimal" => number::decimal_method(name),
+        _ => None,
+    };
+    found.is_some()
+}
+
+/// Find the builtin method `name` of the receiver's type.
+pub(crate) fn builtin_method(this: &ASTKind, name: &str) -> Option<Method> {
+    match this {
+        ASTKind::List(_) => list::method(name),
+        ASTKind::Dict(_) => dict::method(name),
+        ASTKind::String(_) | ASTKind::HTMLText(_) => string::method(name),
+        ASTKind::Integer(_) => number::integer_method(name),
+        ASTKind::Decimal(_) => number::decimal_method(name),
+        _ => None,
+    }
+}
+
+impl MethodCall {
+    pub fn new(this: ASTNode, method: &str, args: CallApply, position: Range) -> Result<Self> {
+        let name = format!("{}.{}", get_variant_name(&this.kind), method);
+        if let Some((key, _)) = args.keywords.first() {
+            let msg = format!("Method `{}` got an unexpected keyword argument `{}`", name, key.as_string());
+            return Err(SDLError::invalid_argument(msg, key.range));
+        }
+        Ok(Self { name, this, arguments: args.arguments, position })
+    }
+    pub fn arity(&self, min: usize, max: usize) -> Result<()> {
+        let given = self.arguments.len();
+        if given >= min && given <= max {
+            return Ok(());
+        }
+        let expected = match min == max {
+            true => format!("{}", min),
+            false => format!("{} to {}", min, max),
+        };
+        let msg = format!("Method `{}` takes {} arguments but {} were given", self.name, expected, given);
+        Err(SDLError::invalid_argument(msg, self.position))
+    }
+    /// Get the receiver, whose type has been checked by [`builtin_method`].
+    pub fn this<T: FromASTNode + Default>(&self) -> T {
+        T::from_node(self.this.to_owned()).unwrap_or_default()
+    }
+    /// Get the `index`-th argument, or `None` if it is not given.
+    pub fn get<T: FromASTNode>(&self, index: usize) -> Result<Option<T>> {
+        let node = match self.arguments.get(index) {
+            Some(s) => s.to_owned(),
+            None => return Ok(None),
+        };
+        if let Some(range) = T::out_of_range(&node) {
+            let msg = format!("Method `{}` argument {} is out of range, expected `{}` from {}", self.name, index + 1, T::type_name(), range);
+            return Err(SDLError::invalid_argument(msg, self.position));
+        }
+        let found = get_variant_name(&node.kind);
+        match T::from_node(node) {
+            Some(s) => Ok(Some(s)),
+            None => {
+                let msg = format!("Method `{}` expected `{}` for argument {}, found `{}`", self.name, T::type_name(), index + 1, found);
+                Err(SDLError::invalid_type(msg, self.position))
+            }
+        }
+    }
+    pub fn output(&self, kind: impl Into<ASTKind>) -> Result<ASTNode> {
+        Ok(ASTNode { kind: kind.into(), range: self.position })
+    }
+}
@@ -0,0 +1,97 @@
+use super::*;
+use bigdecimal::BigDecimal;
+use num::{BigInt, Integer, One, Signed, Zero};
+
+pub(super) fn integer_method(name: &str) -> Option<Method> {
+    let f: Method = match name {
+        "abs" => integer_abs,
+        "div" => integer_div,
+        "to" => integer_to,
+        _ => return None,
+    };
+    Some(f)
+}
+
+pub(super) fn decimal_method(name: &str) -> Option<Method> {
+    let f: Method = match name {
+        "abs" => decimal_abs,
+        "round" => decimal_round,
+        "floor" => decimal_floor,
+        "ceil" => decimal_ceil,
+        _ => return None,
+    };
+    Some(f)
+}
+
+fn integer_abs(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(ASTKind::Integer(Box::new(call.this::<BigInt>().abs())))
+}
+
+/// Integer division rounding towards negative infinity, pairs with `%`.
+fn integer_div(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 1)?;
+    let rhs = call.get::<BigInt>(0)?.unwrap_or_default();
+    if rhs.is_zero() {
+        return Err(SDLError::divide_by_zero(call.position));
+    }
+    call.output(ASTKind::Integer(Box::new(call.this::<BigInt>().div_floor(&rhs))))
+}
+
+/// Ranges longer than this are rejected instead of being built.
+const MAX_RANGE_ITEMS: usize = 1_000_000;
+
+/// `1.to(3)` gives `[1, 2, 3]` and `5.to(1, -2)` gives `[5, 3, 1]`, both ends are included.
+fn integer_to(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 2)?;
+    let end = call.get::<BigInt>(0)?.unwrap_or_default();
+    let step = call.get::<BigInt>(1)?.unwrap_or_else(BigInt::one);
+    if step.is_zero() {
+        let msg = format!("Method `{}` expected a non-zero step", call.name);
+        return Err(SDLError::invalid_argument(msg, call.position));
+    }
+    let mut i = call.this::<BigInt>();
+    let count = ((&end - &i).div_floor(&step) + BigInt::one()).max(BigInt::zero());
+    if count > BigInt::from(MAX_RANGE_ITEMS) {
+        let msg = format!("Method `{}` can give at most {} items, found {}", call.name, MAX_RANGE_ITEMS, count);
+        return Err(SDLError::invalid_argument(msg, call.position));
+    }
+    let mut out = vec![];
+    while (step.is_positive() && i <= end) || (step.is_negative() && i >= end) {
+        out.push(ASTNode::from(ASTKind::Integer(Box::new(i.to_owned()))));
+        i += &step;
+    }
+    call.output(out)
+}
+
+fn decimal_abs(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(ASTKind::Decimal(Box::new(call.this::<BigDecimal>().abs())))
+}
+
+/// Round half away from zero to `n` digits after the decimal point, `n` defaults to 0.
+fn decimal_round(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 1)?;
+    let digits = call.get::<i64>(0)?.unwrap_or(0);
+    call.output(ASTKind::Decimal(Box::new(call.this::<BigDecimal>().round(digits))))
+}
+
+fn decimal_floor(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    let this = call.this::<BigDecimal>();
+    let mut out = this.with_scale(0);
+    if out > this {
+        out -= BigDecimal::from(1);
+    }
+    call.output(ASTKind::Integer(Box::new(out.into_bigint_and_exponent().0)))
+}
+
+fn decimal_ceil(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    let this = call.this::<BigDecimal>();
+    let mut out = this.with_scale(0);
+    if out < this {
+        out += BigDecimal::from(1);
+    }
+    call.output(ASTKind::Integer(Box::new(out.into_bigint_and_exponent().0)))
+}
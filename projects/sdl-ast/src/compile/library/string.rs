@@ -0,0 +1,87 @@
+use super::*;
+
+pub(super) fn method(name: &str) -> Option<Method> {
+    let f: Method = match name {
+        "len" => len,
+        "is_empty" => is_empty,
+        "upper" => upper,
+        "lower" => lower,
+        "trim" => trim,
+        "split" => split,
+        "replace" => replace,
+        "contains" => contains,
+        "starts_with" => starts_with,
+        "ends_with" => ends_with,
+        "safe" => safe,
+        _ => return None,
+    };
+    Some(f)
+}
+
+/// Length in unicode characters.
+fn len(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<String>().chars().count())
+}
+
+fn is_empty(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<String>().is_empty())
+}
+
+fn upper(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<String>().to_uppercase())
+}
+
+fn lower(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<String>().to_lowercase())
+}
+
+fn trim(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(call.this::<String>().trim())
+}
+
+/// Split by `separator`, or by whitespaces if it is not given.
+fn split(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 1)?;
+    let this = call.this::<String>();
+    let parts: Vec<ASTNode> = match call.get::<String>(0)? {
+        Some(separator) => this.split(separator.as_str()).map(ASTNode::from).collect(),
+        None => this.split_whitespace().map(ASTNode::from).collect(),
+    };
+    call.output(parts)
+}
+
+fn replace(call: MethodCall) -> Result<ASTNode> {
+    call.arity(2, 2)?;
+    let from = call.get::<String>(0)?.unwrap_or_default();
+    let to = call.get::<String>(1)?.unwrap_or_default();
+    call.output(call.this::<String>().replace(&from, &to))
+}
+
+fn contains(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 1)?;
+    let pattern = call.get::<String>(0)?.unwrap_or_default();
+    call.output(call.this::<String>().contains(&pattern))
+}
+
+fn starts_with(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 1)?;
+    let pattern = call.get::<String>(0)?.unwrap_or_default();
+    call.output(call.this::<String>().starts_with(&pattern))
+}
+
+fn ends_with(call: MethodCall) -> Result<ASTNode> {
+    call.arity(1, 1)?;
+    let pattern = call.get::<String>(0)?.unwrap_or_default();
+    call.output(call.this::<String>().ends_with(&pattern))
+}
+
+/// Mark a string as already escaped, so it is written into html as is.
+fn safe(call: MethodCall) -> Result<ASTNode> {
+    call.arity(0, 0)?;
+    call.output(ASTKind::HTMLText(call.this::<String>()))
+}
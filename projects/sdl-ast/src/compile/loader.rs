@@ -0,0 +1,77 @@
+use super::*;
+use crate::ast::{IndexMap, ModulePath, Range};
+use std::fmt::{self, Debug, Formatter};
+
+/// Where the source of imported modules comes from.
+pub trait ModuleLoader {
+    /// Find the module `path` imported from the module `base`, returns its id or `None` if there is no such module.
+    ///
+    /// See [`ModulePath::to_id`] for the default layout.
+    fn resolve(&self, path: &ModulePath, base: Option<&str>) -> Option<String>;
+    /// Parse the module with an id given by [`ModuleLoader::resolve`].
+    fn load(&self, id: &str) -> Result<ASTNode>;
+}
+
+/// Modules are evaluated once and shared by every scope which imports them.
+pub(super) struct Importer {
+    loader: Box<dyn ModuleLoader>,
+    cache: RefCell<IndexMap<String, SDLContext>>,
+    /// Modules being evaluated, an import of any of them is circular.
+    loading: RefCell<Vec<String>>,
+}
+
+impl Debug for Importer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Importer").field("cache", &self.cache.borrow().keys()).finish()
+    }
+}
+
+impl Importer {
+    pub fn new(loader: impl ModuleLoader + 'static) -> Self {
+        Self { loader: Box::new(loader), cache: Default::default(), loading: Default::default() }
+    }
+}
+
+impl SDLContext {
+    /// Load the module `path`, returns `None` if the loader can not find it.
+    pub(crate) fn load_module(&self, path: &ModulePath, position: Range) -> Result<Option<SDLContext>> {
+        let importer = match self.importer.as_ref().and_then(|i| i.upgrade()) {
+            Some(s) => s,
+            None => return Err(SDLError::invalid_import(format!("No module loader to import `{:?}`", path), position)),
+        };
+        let id = match importer.loader.resolve(path, self.module_id.as_deref()) {
+            Some(s) => s,
+            None => return Ok(None),
+        };
+        if let Some(module) = importer.cache.borrow().get(&id) {
+            return Ok(Some(module.to_owned()));
+        }
+        if importer.loading.borrow().contains(&id) {
+            let mut chain = importer.loading.borrow().to_owned();
+            chain.push(id);
+            return Err(SDLError::invalid_import(format!("Circular import `{}`", chain.join(" -> ")), position));
+        }
+        let ast = importer.loader.load(&id)?;
+        let mut module = SDLContext {
+            config: self.config.clone(),
+            host: self.host.clone(),
+            diagnostics: self.diagnostics.clone(),
+            importer: Some(Link::Weak(Rc::downgrade(&importer))),
+            module_id: Some(id.to_owned()),
+            calls: self.calls.clone(),
+            ..Default::default()
+        };
+        importer.loading.borrow_mut().push(id.to_owned());
+        let out = module.evaluate(&ast);
+        importer.loading.borrow_mut().pop();
+        out?;
+        importer.cache.borrow_mut().insert(id, module.to_owned());
+        Ok(Some(module))
+    }
+    /// Top level definitions of a module, names starting with `_` are private and imported names are not
+    /// exported again.
+    pub fn exports(&self) -> Vec<(String, ASTNode)> {
+        let imported = self.scope().imported.borrow().to_owned();
+        self.definitions().into_iter().filter(|(k, _)| !k.starts_with('_') && !imported.contains(k)).collect()
+    }
+}
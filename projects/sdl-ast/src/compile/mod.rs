@@ -1,37 +1,141 @@
-// mod value;
+mod annotation;
+mod diagnostic;
+pub(crate) mod library;
+mod loader;
+mod native;
+mod string_handler;
 mod variable;
 
 use crate::{
+    ast::{Class, Function, Generator, IndexMap, Object, Range, Signal, Symbol},
     traits::{Evaluate, Render},
-    Result, ASTNode,
+    ASTKind, Result, ASTNode, SDLError,
 };
+use indexmap::IndexSet;
 use std::{
-    collections::HashMap,
+    cell::{Cell, RefCell},
+    fmt::{self, Debug, Formatter},
     rc::{Rc, Weak},
 };
+use annotation::AnnotationHandler;
+pub use annotation::Annotated;
+pub use diagnostic::WarningLevel;
+use loader::Importer;
+pub use loader::ModuleLoader;
+pub use native::{FromASTNode, IntoNativeFunction};
+use string_handler::StringHandler;
+pub use string_handler::TaggedString;
 pub use variable::Variable;
 
-#[derive(Clone, Debug)]
+/// A lexical scope.
+///
+/// Cloning a context is cheap and the clone shares the same scope, so a [`SDLContext::fork`]ed child can still
+/// see (and later mutate) everything that is visible from its parents.
+#[derive(Clone, Debug, Default)]
 pub struct SDLContext {
     config: Option<Box<SDLContextConfig>>,
-    father: Option<Weak<SDLContext>>,
-    variables: HashMap<String, ASTNode>,
+    father: Option<Rc<SDLContext>>,
+    scope: Link<Scope>,
+    /// Functions, annotations and string handlers registered by the host, visible from every scope and module.
+    host: Rc<Scope>,
+    /// Warnings collected from every scope and module of an evaluation.
+    diagnostics: Rc<RefCell<Vec<SDLError>>>,
+    /// Held weakly by modules, which are cached by the importer.
+    importer: Option<Link<Importer>>,
+    /// Id of the module being evaluated, relative imports are resolved from it.
+    module_id: Option<String>,
+    /// Consumer of `yield` while the body of a generator is running.
+    generator: Option<YieldHandler>,
+    /// Number of function calls being evaluated, shared by every scope and module of an evaluation.
+    calls: Rc<Cell<usize>>,
 }
 
-#[derive(Clone, Debug)]
-pub struct SDLContextConfig {
-    pub is_debug: bool,
+/// Definitions of a single scope.
+#[derive(Debug, Default)]
+struct Scope {
+    variables: RefCell<IndexMap<String, Variable>>,
+    modules: RefCell<IndexMap<String, SDLContext>>,
+    /// Names brought in by `import`, which are not exported again.
+    imported: RefCell<IndexSet<String>>,
+    /// Annotations registered by the host, only used by the host scope.
+    annotations: RefCell<IndexMap<String, AnnotationHandler>>,
+    /// String handlers registered by the host, only used by the host scope.
+    string_handlers: RefCell<IndexMap<String, StringHandler>>,
+    /// Methods added to types by `extend`, by type name and then by method name.
+    extensions: RefCell<IndexMap<String, IndexMap<String, ASTNode>>>,
+}
+
+/// A shared value which is held weakly where holding it would create a cycle, eg: functions stored in a scope
+/// hold that scope weakly, see [`SDLContext::detach`].
+#[derive(Debug)]
+enum Link<T> {
+    Strong(Rc<T>),
+    Weak(Weak<T>),
+}
+
+impl<T> Clone for Link<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Link::Strong(s) => Link::Strong(s.to_owned()),
+            Link::Weak(s) => Link::Weak(s.to_owned()),
+        }
+    }
 }
 
-impl Default for SDLContext {
+impl<T: Default> Default for Link<T> {
     fn default() -> Self {
-        Self { config: None, father: None, variables: Default::default() }
+        Link::Strong(Default::default())
+    }
+}
+
+impl<T> Link<T> {
+    fn upgrade(&self) -> Option<Rc<T>> {
+        match self {
+            Link::Strong(s) => Some(s.to_owned()),
+            Link::Weak(s) => s.upgrade(),
+        }
+    }
+    fn downgrade(&self) -> Link<T> {
+        match self {
+            Link::Strong(s) => Link::Weak(Rc::downgrade(s)),
+            Link::Weak(s) => Link::Weak(s.to_owned()),
+        }
+    }
+}
+
+/// Leaves a function call when dropped, see [`SDLContext::enter_call`].
+pub(crate) struct CallGuard(Rc<Cell<usize>>);
+
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1)
+    }
+}
+
+/// Runs the loop body for each yielded value, a returned signal stops the generator.
+#[derive(Clone)]
+struct YieldHandler(Rc<dyn Fn(ASTNode) -> Result<Option<Signal>>>);
+
+impl Debug for YieldHandler {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("YieldHandler")
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct SDLContextConfig {
+    pub warnings: WarningLevel,
+    /// Significant digits kept by inexact decimal operations such as `1 / 3` or `√2`.
+    pub precision: u64,
+    /// Evaluate `#test` statements, which are skipped by default.
+    pub run_tests: bool,
+    /// Function calls nested deeper than this raise an error instead of overflowing the stack.
+    pub max_call_depth: usize,
+}
+
 impl Default for SDLContextConfig {
     fn default() -> Self {
-        Self { is_debug: false }
+        Self { warnings: WarningLevel::Warn, precision: 20, run_tests: false, max_call_depth: 1000 }
     }
 }
 
@@ -45,11 +149,90 @@ impl SDLContext {
         Ok(output)
     }
 
+    /// Define a constant in the current scope, shadowing any outer definition.
     pub fn insert(&mut self, key: &str, v: impl Into<ASTNode>) {
-        self.variables.insert(key.to_string(), v.into());
+        self.declare(key, Variable::Constant(v.into()));
+    }
+    pub fn declare(&mut self, key: &str, v: Variable) {
+        let scope = self.scope();
+        let v = v.map(|v| detach_value(v, &scope));
+        scope.imported.borrow_mut().shift_remove(key);
+        scope.variables.borrow_mut().insert(key.to_string(), v);
+    }
+    /// Get the value of `key`, or `null` if it is not defined in any visible scope.
+    pub fn get(&self, key: &str) -> ASTNode {
+        self.get_variable(key).map(|v| v.get()).unwrap_or_default()
+    }
+    /// Overwrite the nearest definition of `key`, returns `false` if `key` is not defined in any visible scope.
+    pub fn update(&mut self, key: &str, v: ASTNode) -> bool {
+        let scope = self.scope();
+        if let Some(old) = scope.variables.borrow_mut().get_mut(key) {
+            *old = Variable::Variable(detach_value(v, &scope));
+            return true;
+        }
+        match &self.father {
+            Some(father) => father.as_ref().clone().update(key, v),
+            None => false,
+        }
+    }
+    /// Values defined in the current scope itself, in the order of definition.
+    pub fn definitions(&self) -> Vec<(String, ASTNode)> {
+        self.scope().variables.borrow().iter().map(|(k, v)| (k.to_owned(), v.get())).collect()
+    }
+    /// Search `key` from the current scope up to the root scope, then in the functions registered by the host.
+    pub fn get_variable(&self, key: &str) -> Option<Variable> {
+        if let Some(v) = self.scope().variables.borrow().get(key) {
+            return Some(v.to_owned());
+        }
+        match &self.father {
+            Some(father) => father.get_variable(key),
+            None => self.host.variables.borrow().get(key).cloned(),
+        }
     }
-    pub fn get(&mut self, key: &str) -> ASTNode {
-        self.variables.get(key).cloned().unwrap_or_default()
+    /// Define a value brought in by `import`, see [`SDLContext::exports`].
+    pub(crate) fn import(&mut self, key: &str, v: ASTNode) {
+        self.insert(key, v);
+        self.scope().imported.borrow_mut().insert(key.to_string());
+    }
+
+    /// Mount a module under `name`, its members can be accessed by `name::member`.
+    pub fn insert_module(&mut self, name: &str, module: SDLContext) {
+        self.scope().modules.borrow_mut().insert(name.to_string(), module);
+    }
+    pub fn get_module(&self, name: &str) -> Option<SDLContext> {
+        if let Some(m) = self.scope().modules.borrow().get(name) {
+            return Some(m.to_owned());
+        }
+        self.father.as_ref().and_then(|ctx| ctx.get_module(name))
+    }
+
+    /// Add a method to values of type `type_name` in the current scope, see [`ASTNode::get_type`].
+    pub fn extend_type(&mut self, type_name: &str, name: &str, method: ASTNode) {
+        let scope = self.scope();
+        let method = detach_value(method, &scope);
+        scope.extensions.borrow_mut().entry(type_name.to_string()).or_default().insert(name.to_string(), method);
+    }
+    /// Search the method `name` added to `type_name` from the current scope up to the root scope.
+    pub fn get_extension(&self, type_name: &str, name: &str) -> Option<ASTNode> {
+        if let Some(m) = self.scope().extensions.borrow().get(type_name).and_then(|methods| methods.get(name)) {
+            return Some(attach_value(m.to_owned()));
+        }
+        self.father.as_ref().and_then(|ctx| ctx.get_extension(type_name, name))
+    }
+
+    /// Resolve a possibly namespaced symbol, `a::b::c` looks up `c` in module `b` of module `a`.
+    pub fn resolve(&self, symbol: &Symbol) -> Option<ASTNode> {
+        let mut namespace = symbol.namespace().into_iter();
+        let mut module = match namespace.next() {
+            None => return self.get_variable(&symbol.name()).map(|v| v.get()),
+            Some(head) => self.get_module(&head)?,
+        };
+        for name in namespace {
+            let next = module.scope().modules.borrow().get(&name).cloned()?;
+            module = next;
+        }
+        let out = module.scope().variables.borrow().get(&symbol.name()).map(|v| v.get());
+        out
     }
 
     pub fn config(&self) -> SDLContextConfig {
@@ -58,18 +241,145 @@ impl SDLContext {
             Some(x) => *x.clone(),
         }
     }
+    /// Scopes forked after this call inherit the new config.
+    pub fn set_config(&mut self, config: SDLContextConfig) {
+        self.config = Some(Box::new(config))
+    }
+
+    /// Modules imported after this call, and modules imported by them, are loaded by `loader`.
+    pub fn set_loader(&mut self, loader: impl ModuleLoader + 'static) {
+        self.importer = Some(Link::Strong(Rc::new(Importer::new(loader))))
+    }
+
+    /// `yield` in this scope, and in scopes forked after this call, passes the value to `handler`.
+    pub(crate) fn set_yield_handler(&mut self, handler: impl Fn(ASTNode) -> Result<Option<Signal>> + 'static) {
+        self.generator = Some(YieldHandler(Rc::new(handler)))
+    }
+    pub(crate) fn yield_value(&self, value: ASTNode, position: Range) -> Result<Option<Signal>> {
+        match &self.generator {
+            Some(handler) => (handler.0)(value),
+            None => Err(SDLError::invalid_operation("`yield` outside of a generator", position)),
+        }
+    }
 
+    /// Create a child scope, definitions in the child are dropped with it.
     pub fn fork(&self) -> SDLContext {
-        let new = Rc::downgrade(&Rc::new(self.to_owned()));
-        SDLContext { config: None, father: Some(new), variables: Default::default() }
+        SDLContext {
+            config: self.config.clone(),
+            father: Some(Rc::new(self.to_owned())),
+            scope: Default::default(),
+            host: self.host.clone(),
+            diagnostics: self.diagnostics.clone(),
+            importer: self.importer.clone(),
+            module_id: self.module_id.clone(),
+            generator: self.generator.clone(),
+            calls: self.calls.clone(),
+        }
+    }
+
+    fn scope(&self) -> Rc<Scope> {
+        // a scope which is gone is gone with everything defined in it
+        self.scope.upgrade().unwrap_or_default()
+    }
+    /// A copy which holds `scope` and its outer scopes weakly, returns `None` if `scope` is not visible from this
+    /// context.
+    ///
+    /// Everything that can read a value from `scope` also holds the outer scopes of `scope`.
+    fn detach(&self, scope: &Rc<Scope>) -> Option<SDLContext> {
+        match &self.scope {
+            Link::Strong(s) if Rc::ptr_eq(s, scope) => Some(self.weaken()),
+            _ => {
+                let father = self.father.as_ref()?.detach(scope)?;
+                Some(SDLContext { father: Some(Rc::new(father)), ..self.to_owned() })
+            }
+        }
+    }
+    fn weaken(&self) -> SDLContext {
+        SDLContext { father: self.father.as_ref().map(|f| Rc::new(f.weaken())), scope: self.scope.downgrade(), ..self.to_owned() }
+    }
+    /// A copy which holds every scope strongly again, returns `None` if there is nothing to change.
+    fn attach(&self) -> Option<SDLContext> {
+        let father = self.father.as_ref().and_then(|f| f.attach());
+        let scope = match &self.scope {
+            Link::Weak(s) => s.upgrade().map(Link::Strong),
+            Link::Strong(_) => None,
+        };
+        if father.is_none() && scope.is_none() {
+            return None;
+        }
+        Some(SDLContext {
+            father: father.map(Rc::new).or_else(|| self.father.to_owned()),
+            scope: scope.unwrap_or_else(|| self.scope.to_owned()),
+            ..self.to_owned()
+        })
+    }
+
+    /// Count a function call until the guard is dropped, fails if calls are nested too deep.
+    pub(crate) fn enter_call(&self, position: Range) -> Result<CallGuard> {
+        let limit = self.config().max_call_depth;
+        if self.calls.get() >= limit {
+            return Err(SDLError::recursion_limit(limit, position));
+        }
+        self.calls.set(self.calls.get() + 1);
+        Ok(CallGuard(self.calls.to_owned()))
     }
 }
 
-impl SDLContext {
-    // pub fn get_value(&self, name: &str) -> Value {
-    //     match self.variables.get(name) {
-    //         Some(v) => v.get(),
-    //         None => self.father.as_ref().and_then(|ctx| ctx.upgrade()).map(|ctx| ctx.get_value(name)).unwrap_or_default(),
-    //     }
-    // }
+/// Make a value stored in `scope` hold `scope` weakly.
+fn detach_value(value: ASTNode, scope: &Rc<Scope>) -> ASTNode {
+    let kind = match &value.kind {
+        ASTKind::Function(f) => match f.env.detach(scope) {
+            Some(env) => ASTKind::Function(Box::new(Function { define: f.define.to_owned(), env })),
+            None => return value,
+        },
+        ASTKind::Generator(g) => match (g.function.env.detach(scope), g.scope.detach(scope)) {
+            (Some(env), Some(call)) => {
+                let function = Function { define: g.function.define.to_owned(), env };
+                ASTKind::Generator(Box::new(Generator { function, scope: call }))
+            }
+            _ => return value,
+        },
+        ASTKind::NativeFunction(f) => match &f.bound {
+            Some(bound) => ASTKind::NativeFunction(Box::new(f.as_ref().to_owned().bind(detach_value(*bound.to_owned(), scope)))),
+            None => return value,
+        },
+        ASTKind::Class(c) => ASTKind::Class(map_class(c, |v| detach_value(v, scope))),
+        ASTKind::Object(o) => {
+            ASTKind::Object(Box::new(Object { class: map_class(&o.class, |v| detach_value(v, scope)), fields: o.fields.to_owned() }))
+        }
+        _ => return value,
+    };
+    ASTNode { kind, range: value.range }
+}
+
+/// Make a value read from a scope hold its scopes strongly again.
+fn attach_value(value: ASTNode) -> ASTNode {
+    let kind = match &value.kind {
+        ASTKind::Function(f) => match f.env.attach() {
+            Some(env) => ASTKind::Function(Box::new(Function { define: f.define.to_owned(), env })),
+            None => return value,
+        },
+        ASTKind::Generator(g) => match (g.function.env.attach(), g.scope.attach()) {
+            (Some(env), Some(call)) => {
+                let function = Function { define: g.function.define.to_owned(), env };
+                ASTKind::Generator(Box::new(Generator { function, scope: call }))
+            }
+            _ => return value,
+        },
+        ASTKind::NativeFunction(f) => match &f.bound {
+            Some(bound) => ASTKind::NativeFunction(Box::new(f.as_ref().to_owned().bind(attach_value(*bound.to_owned())))),
+            None => return value,
+        },
+        ASTKind::Class(c) => ASTKind::Class(map_class(c, attach_value)),
+        ASTKind::Object(o) => ASTKind::Object(Box::new(Object { class: map_class(&o.class, attach_value), fields: o.fields.to_owned() })),
+        _ => return value,
+    };
+    ASTNode { kind, range: value.range }
+}
+
+/// Apply `f` to the methods and defaults of a class, which hold the scope where the class was defined.
+fn map_class(class: &Rc<Class>, f: impl Fn(ASTNode) -> ASTNode) -> Rc<Class> {
+    let fields = class.fields.iter().map(|(k, v)| (k.to_owned(), v.to_owned().map(&f))).collect();
+    let methods = class.methods.iter().map(|(k, v)| (k.to_owned(), f(v.to_owned()))).collect();
+    Rc::new(Class { name: class.name.to_owned(), fields, methods })
 }
@@ -0,0 +1,248 @@
+use super::*;
+use crate::{
+    ast::{CallApply, IndexMap, NativeFunction, Range},
+    utils::get_variant_name,
+    ASTKind, SDLError,
+};
+use bigdecimal::BigDecimal;
+use num::{BigInt, ToPrimitive};
+
+/// Types that can be taken out of an argument of a native function.
+pub trait FromASTNode: Sized {
+    /// Type name used in error messages.
+    fn type_name() -> String;
+    fn from_node(node: ASTNode) -> Option<Self>;
+    /// The range that fits if `node` has the right type but does not fit, eg: an `Integer` beyond `i64`.
+    fn out_of_range(_: &ASTNode) -> Option<String> {
+        None
+    }
+}
+
+/// Rust closures that can be registered by [`SDLContext::register_fn`].
+///
+/// Implemented for `Fn(A, B, ..) -> Result<R>` with up to 5 arguments, where every argument is [`FromASTNode`].
+pub trait IntoNativeFunction<Args> {
+    fn into_native(self, name: &str) -> NativeFunction;
+}
+
+impl SDLContext {
+    /// Register a Rust closure as a function, the arity and argument types are checked at the call site.
+    ///
+    /// ```
+    /// # use sdl_ast::SDLContext;
+    /// let mut ctx = SDLContext::default();
+    /// ctx.register_fn("slugify", |s: String| Ok(s.to_lowercase().replace(' ', "-")));
+    /// ```
+    pub fn register_fn<Args>(&mut self, name: &str, f: impl IntoNativeFunction<Args>) {
+        self.register_native(f.into_native(name))
+    }
+    /// Register a Rust closure which takes all positional and keyword arguments as they are.
+    pub fn register_raw_fn(&mut self, name: &str, f: impl Fn(CallApply, Range) -> Result<ASTNode> + 'static) {
+        self.register_native(NativeFunction::new(name, f))
+    }
+    /// Natives are visible from every scope and imported module, a definition with the same name shadows them.
+    fn register_native(&mut self, f: NativeFunction) {
+        let value = ASTNode { kind: ASTKind::NativeFunction(Box::new(f.to_owned())), range: Default::default() };
+        self.host.variables.borrow_mut().insert(f.name, Variable::Constant(value));
+    }
+}
+
+fn check_arguments(name: &str, arity: usize, args: &CallApply, position: Range) -> Result<()> {
+    if let Some((key, _)) = args.keywords.first() {
+        let msg = format!("Function `{}` got an unexpected keyword argument `{}`", name, key.as_string());
+        return Err(SDLError::invalid_argument(msg, key.range));
+    }
+    if args.arguments.len() != arity {
+        let msg = format!("Function `{}` takes {} arguments but {} were given", name, arity, args.arguments.len());
+        return Err(SDLError::invalid_argument(msg, position));
+    }
+    Ok(())
+}
+
+fn convert_argument<T: FromASTNode>(name: &str, index: usize, node: ASTNode, position: Range) -> Result<T> {
+    if let Some(range) = T::out_of_range(&node) {
+        let msg = format!("Function `{}` argument {} is out of range, expected `{}` from {}", name, index + 1, T::type_name(), range);
+        return Err(SDLError::invalid_argument(msg, position));
+    }
+    let found = get_variant_name(&node.kind);
+    match T::from_node(node) {
+        Some(s) => Ok(s),
+        None => {
+            let msg = format!("Function `{}` expected `{}` for argument {}, found `{}`", name, T::type_name(), index + 1, found);
+            Err(SDLError::invalid_type(msg, position))
+        }
+    }
+}
+
+macro_rules! count {
+    () => {0usize};
+    ($head:ident $($tail:ident)*) => {1usize + count!($($tail)*)};
+}
+
+macro_rules! impl_native {
+    ($($A:ident),*) => {
+        #[allow(non_snake_case, unused_mut, unused_variables)]
+        impl<F, R, $($A,)*> IntoNativeFunction<($($A,)*)> for F
+        where
+            F: Fn($($A),*) -> Result<R> + 'static,
+            R: Into<ASTNode>,
+            $($A: FromASTNode,)*
+        {
+            fn into_native(self, name: &str) -> NativeFunction {
+                let name = name.to_string();
+                NativeFunction::new(name.clone(), move |args: CallApply, position: Range| {
+                    check_arguments(&name, count!($($A)*), &args, position)?;
+                    let mut args = args.arguments.into_iter().enumerate();
+                    $(
+                        let (index, node) = args.next().unwrap_or_default();
+                        let $A = convert_argument::<$A>(&name, index, node, position)?;
+                    )*
+                    Ok(self($($A),*)?.into())
+                })
+            }
+        }
+    };
+}
+
+impl_native!();
+impl_native!(A);
+impl_native!(A, B);
+impl_native!(A, B, C);
+impl_native!(A, B, C, D);
+impl_native!(A, B, C, D, E);
+
+impl FromASTNode for ASTNode {
+    fn type_name() -> String {
+        String::from("Any")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        Some(node)
+    }
+}
+
+impl FromASTNode for bool {
+    fn type_name() -> String {
+        String::from("Boolean")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        match node.kind {
+            ASTKind::Boolean(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl FromASTNode for String {
+    fn type_name() -> String {
+        String::from("String")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        match node.kind {
+            ASTKind::String(v) | ASTKind::HTMLText(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl FromASTNode for BigInt {
+    fn type_name() -> String {
+        String::from("Integer")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        match node.kind {
+            ASTKind::Integer(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl FromASTNode for i64 {
+    fn type_name() -> String {
+        String::from("Integer")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        BigInt::from_node(node)?.to_i64()
+    }
+    fn out_of_range(node: &ASTNode) -> Option<String> {
+        match &node.kind {
+            ASTKind::Integer(v) if v.to_i64().is_none() => Some(format!("{} to {}", i64::MIN, i64::MAX)),
+            _ => None,
+        }
+    }
+}
+
+impl FromASTNode for usize {
+    fn type_name() -> String {
+        String::from("Integer")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        BigInt::from_node(node)?.to_usize()
+    }
+    fn out_of_range(node: &ASTNode) -> Option<String> {
+        match &node.kind {
+            ASTKind::Integer(v) if v.to_usize().is_none() => Some(format!("0 to {}", usize::MAX)),
+            _ => None,
+        }
+    }
+}
+
+impl FromASTNode for BigDecimal {
+    fn type_name() -> String {
+        String::from("Decimal")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        match node.kind {
+            ASTKind::Integer(v) => Some(BigDecimal::from(*v)),
+            ASTKind::Decimal(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl FromASTNode for f64 {
+    fn type_name() -> String {
+        String::from("Decimal")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        BigDecimal::from_node(node)?.to_f64()
+    }
+}
+
+impl FromASTNode for Vec<ASTNode> {
+    fn type_name() -> String {
+        String::from("List")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        match node.kind {
+            ASTKind::List(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl FromASTNode for IndexMap<String, ASTNode> {
+    fn type_name() -> String {
+        String::from("Dict")
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        match node.kind {
+            ASTKind::Dict(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl<T: FromASTNode> FromASTNode for Option<T> {
+    fn type_name() -> String {
+        format!("{}?", T::type_name())
+    }
+    fn from_node(node: ASTNode) -> Option<Self> {
+        match node.kind {
+            ASTKind::Null => Some(None),
+            _ => Some(Some(T::from_node(node)?)),
+        }
+    }
+    fn out_of_range(node: &ASTNode) -> Option<String> {
+        T::out_of_range(node)
+    }
+}
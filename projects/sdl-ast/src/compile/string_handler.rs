@@ -0,0 +1,251 @@
+use super::*;
+use crate::{
+    utils::{escape_attribute, escape_quotes, is_script_url, UNSAFE_URL, URL_ATTRIBUTES},
+    ASTKind,
+};
+use pulldown_cmark::{html::push_html, CowStr, Event, Options, Parser, Tag};
+
+/// A string with a handler, eg: `html"<b>{{ name }}</b>"`.
+///
+/// Like tagged templates, there is always one more piece than values, the pieces are `["<b>", "</b>"]` and the
+/// values are `[name]` in the example.
+#[derive(Clone, Debug)]
+pub struct TaggedString {
+    /// Name of the handler.
+    pub handler: String,
+    /// Literal pieces of the string.
+    pub pieces: Vec<String>,
+    /// Evaluated interpolations.
+    pub values: Vec<ASTNode>,
+    /// Range of the handler name.
+    pub position: Range,
+}
+
+/// Returns the value of the string.
+type StringHandlerFn = dyn Fn(TaggedString, &SDLContext) -> Result<ASTNode>;
+
+#[derive(Clone)]
+pub(crate) struct StringHandler(Rc<StringHandlerFn>);
+
+impl Debug for StringHandler {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("StringHandler")
+    }
+}
+
+impl StringHandler {
+    pub fn call(&self, target: TaggedString, ctx: &SDLContext) -> Result<ASTNode> {
+        (self.0)(target, ctx)
+    }
+}
+
+impl SDLContext {
+    /// Register a string handler implemented by the host, it shadows the builtin handler with the same name and
+    /// can be used in every scope and imported module.
+    ///
+    /// ```
+    /// # use sdl_ast::{ASTNode, SDLContext};
+    /// let mut ctx = SDLContext::default();
+    /// ctx.register_string_handler("upper", |s, _| Ok(ASTNode::string(s.pieces.concat().to_uppercase(), s.position)));
+    /// ```
+    pub fn register_string_handler(&mut self, name: &str, f: impl Fn(TaggedString, &SDLContext) -> Result<ASTNode> + 'static) {
+        self.host.string_handlers.borrow_mut().insert(name.to_string(), StringHandler(Rc::new(f)));
+    }
+    /// Search the string handler `name` in the handlers registered by the host, then in the builtins.
+    pub(crate) fn get_string_handler(&self, name: &str) -> Option<StringHandler> {
+        let found = self.host.string_handlers.borrow().get(name).cloned();
+        found.or_else(|| builtin_string_handler(name))
+    }
+}
+
+fn builtin_string_handler(name: &str) -> Option<StringHandler> {
+    let f = match name {
+        "raw" => raw,
+        "html" => html,
+        "md" => markdown,
+        "re" => pattern,
+        _ => return None,
+    };
+    Some(StringHandler(Rc::new(f)))
+}
+
+impl TaggedString {
+    /// Join the pieces with the values transformed by `f`.
+    fn join(&self, ctx: &SDLContext, f: impl Fn(&ASTNode, String) -> String) -> Result<String> {
+        let mut out = String::new();
+        for (i, piece) in self.pieces.iter().enumerate() {
+            out.push_str(piece);
+            if let Some(value) = self.values.get(i) {
+                out.push_str(&f(value, value_text(value, ctx)?));
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Strings are inserted without quotes, other values as they are rendered.
+fn value_text(value: &ASTNode, ctx: &SDLContext) -> Result<String> {
+    match &value.kind {
+        ASTKind::String(s) | ASTKind::HTMLText(s) => Ok(s.to_owned()),
+        _ => {
+            let mut out = String::new();
+            value.render(&mut out, ctx)?;
+            Ok(out)
+        }
+    }
+}
+
+/// Everything is trusted, `raw"<b>{{ x }}</b>"` is never escaped.
+fn raw(target: TaggedString, ctx: &SDLContext) -> Result<ASTNode> {
+    let out = target.join(ctx, |_, text| text)?;
+    Ok(ASTNode::string_escaped(out, target.position))
+}
+
+/// Pieces are trusted, values are escaped for where they are placed, see [`HTMLContext`].
+///
+/// Between tags, values which are already html are kept, quotes of other values are escaped too, so the result
+/// stays safe when it is used as an attribute. In attributes, values are escaped as attributes of elements are,
+/// and script urls in [`URL_ATTRIBUTES`] are replaced.
+fn html(target: TaggedString, ctx: &SDLContext) -> Result<ASTNode> {
+    let mut out = String::new();
+    let mut context = HTMLContext::Text;
+    for (i, piece) in target.pieces.iter().enumerate() {
+        out.push_str(piece);
+        piece.chars().for_each(|c| context.step(c));
+        let value = match target.values.get(i) {
+            Some(s) => s,
+            None => continue,
+        };
+        let text = value_text(value, ctx)?;
+        if let HTMLContext::BeforeValue(name) = &mut context {
+            // the value starts an unquoted attribute value
+            context = HTMLContext::Value { name: std::mem::take(name), quote: None, value: String::new() };
+        }
+        let text = match (&mut context, &value.kind) {
+            (HTMLContext::Text, ASTKind::HTMLText(_) | ASTKind::HTMLElement(_)) => text,
+            (HTMLContext::Text, _) => escape_attribute(&text),
+            (HTMLContext::Value { name, quote, value: before }, kind) => {
+                let url = URL_ATTRIBUTES.contains(&name.as_str()) && is_script_url(&format!("{}{}", before, text));
+                let text = match (url, kind) {
+                    (true, _) => UNSAFE_URL.to_string(),
+                    (false, ASTKind::HTMLText(_)) => escape_quotes(&text),
+                    (false, _) if quote.is_some() => escape_attribute(&text),
+                    (false, _) => escape_unquoted(&text),
+                };
+                before.push_str(&text);
+                text
+            }
+            // a value placed among attribute names
+            _ => escape_unquoted(&text),
+        };
+        out.push_str(&text);
+    }
+    Ok(ASTNode::string_escaped(out, target.position))
+}
+
+/// Where a value is placed in the html written so far, comments and raw text elements are not recognized.
+enum HTMLContext {
+    /// Between tags.
+    Text,
+    /// Inside a tag, before an attribute name.
+    Tag,
+    /// Reading an attribute name.
+    Name(String),
+    /// After an attribute name, before `=` or the next attribute.
+    AfterName(String),
+    /// After `=`, before the attribute value.
+    BeforeValue(String),
+    /// Reading the value of attribute `name`, `value` holds what has been read so far.
+    Value { name: String, quote: Option<char>, value: String },
+}
+
+impl HTMLContext {
+    fn step(&mut self, c: char) {
+        let next = match self {
+            Self::Text if c == '<' => Self::Tag,
+            Self::Text => return,
+            _ if c == '>' && !matches!(self, Self::Value { quote: Some(_), .. }) => Self::Text,
+            Self::Tag if c.is_whitespace() || c == '/' => return,
+            Self::Tag | Self::AfterName(_) if c == '=' => return,
+            Self::Tag => Self::Name(c.to_ascii_lowercase().to_string()),
+            Self::Name(name) if c == '=' => Self::BeforeValue(std::mem::take(name)),
+            Self::Name(name) if c.is_whitespace() => Self::AfterName(std::mem::take(name)),
+            Self::Name(_) if c == '/' => Self::Tag,
+            Self::Name(name) => return name.push(c.to_ascii_lowercase()),
+            Self::AfterName(name) if c == '=' => Self::BeforeValue(std::mem::take(name)),
+            Self::AfterName(_) if c.is_whitespace() => return,
+            Self::AfterName(_) => Self::Name(c.to_ascii_lowercase().to_string()),
+            Self::BeforeValue(_) if c.is_whitespace() => return,
+            Self::BeforeValue(name) if c == '"' || c == '\'' => {
+                Self::Value { name: std::mem::take(name), quote: Some(c), value: String::new() }
+            }
+            Self::BeforeValue(name) => Self::Value { name: std::mem::take(name), quote: None, value: c.to_string() },
+            Self::Value { quote: Some(q), .. } if c == *q => Self::Tag,
+            Self::Value { quote: None, .. } if c.is_whitespace() => Self::Tag,
+            Self::Value { value, .. } => return value.push(c),
+        };
+        *self = next;
+    }
+}
+
+/// Escape a value which is not enclosed in quotes, so it cannot end the attribute or add another one.
+fn escape_unquoted(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in escape_attribute(input).chars() {
+        match c {
+            '=' | '`' | '/' => out.push_str(&format!("&#{};", c as u32)),
+            _ if c.is_whitespace() => out.push_str(&format!("&#{};", c as u32)),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Renders CommonMark with tables and strikethrough, script urls are replaced.
+///
+/// Values are text, markdown and html in them are escaped unless they are already html.
+fn markdown(target: TaggedString, ctx: &SDLContext) -> Result<ASTNode> {
+    let source = target.join(ctx, |value, text| match value.kind {
+        ASTKind::HTMLText(_) | ASTKind::HTMLElement(_) => text,
+        _ => escape_markdown(&text),
+    })?;
+    let mut out = String::with_capacity(source.len() * 3 / 2);
+    let events = Parser::new_ext(&source, Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH).map(|event| match event {
+        Event::Start(Tag::Link(kind, url, title)) => Event::Start(Tag::Link(kind, safe_url(url), title)),
+        Event::Start(Tag::Image(kind, url, title)) => Event::Start(Tag::Image(kind, safe_url(url), title)),
+        _ => event,
+    });
+    push_html(&mut out, events);
+    Ok(ASTNode::string_escaped(out, target.position))
+}
+
+/// Every ascii punctuation can be escaped by a backslash, which also covers `<` and `&` of html.
+fn escape_markdown(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        if c.is_ascii_punctuation() {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn safe_url(url: CowStr) -> CowStr {
+    match is_script_url(&url) {
+        true => CowStr::Borrowed(UNSAFE_URL),
+        false => url,
+    }
+}
+
+/// Values match literally, the pattern is checked and returned as a string.
+fn pattern(target: TaggedString, ctx: &SDLContext) -> Result<ASTNode> {
+    let pattern = target.join(ctx, |_, text| regex::escape(&text))?;
+    if let Err(e) = regex::Regex::new(&pattern) {
+        let reason = e.to_string().lines().last().unwrap_or_default().trim_start_matches("error: ").to_string();
+        let msg = format!("String handler `re` got an invalid pattern, {}", reason);
+        return Err(SDLError::invalid_argument(msg, target.position));
+    }
+    Ok(ASTNode::string(pattern, target.position))
+}
+
@@ -11,10 +11,18 @@ pub enum Variable {
 impl Variable {
     pub fn get(&self) -> ASTNode {
         match self {
-            Variable::Constant(v) => v.to_owned(),
-            Variable::Variable(v) => v.to_owned(),
-            Variable::Delay(v) => v.to_owned(),
-            Variable::Lazy(v) => v.to_owned(),
+            Variable::Constant(v) => attach_value(v.to_owned()),
+            Variable::Variable(v) => attach_value(v.to_owned()),
+            Variable::Delay(v) => attach_value(v.to_owned()),
+            Variable::Lazy(v) => attach_value(v.to_owned()),
+        }
+    }
+    pub(super) fn map(self, f: impl FnOnce(ASTNode) -> ASTNode) -> Variable {
+        match self {
+            Variable::Constant(v) => Variable::Constant(f(v)),
+            Variable::Variable(v) => Variable::Variable(f(v)),
+            Variable::Delay(v) => Variable::Delay(f(v)),
+            Variable::Lazy(v) => Variable::Lazy(f(v)),
         }
     }
 }
@@ -9,7 +9,10 @@ pub struct SDLError {
 
 #[derive(Debug, Clone)]
 pub enum SDLErrorKind {
-    FileNotFound(String),
+    FileNotFound {
+        path: String,
+        position: Range,
+    },
     InvalidOperation {
         info: String,
         position: Range,
@@ -23,24 +26,112 @@ pub enum SDLErrorKind {
         item_type: String,
         position: Range,
     },
+    UndefinedSymbol {
+        symbol: String,
+        position: Range,
+    },
+    NotCallable {
+        item_type: String,
+        position: Range,
+    },
+    InvalidArgument {
+        info: String,
+        position: Range,
+    },
+    InvalidType {
+        info: String,
+        position: Range,
+    },
+    ImmutableVariable {
+        symbol: String,
+        position: Range,
+    },
+    InvalidPattern {
+        info: String,
+        position: Range,
+    },
     IfLost {
         position: Range,
     },
-    LexerError {
-      info: String
+    DivideByZero {
+        position: Range,
+    },
+    InvalidTemplate {
+        info: String,
+        position: Range,
+    },
+    InvalidImport {
+        info: String,
+        position: Range,
+    },
+    Deprecated {
+        info: String,
+        position: Range,
+    },
+    /// Function calls are nested deeper than [`SDLContextConfig::max_call_depth`](crate::SDLContextConfig).
+    RecursionLimit {
+        limit: usize,
+        position: Range,
+    },
+    /// The source cannot be parsed.
+    SyntaxError {
+        code: SyntaxErrorCode,
+        info: String,
+        /// Names of the grammar rules that would have been accepted at `position`.
+        expected: Vec<String>,
+        position: Range,
     },
     FormatError(std::fmt::Error),
 }
 
+/// Stable codes of syntax errors, a code keeps its meaning once released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxErrorCode {
+    /// `S0001`, the input does not match the grammar.
+    UnexpectedToken,
+    /// `S0002`, the grammar accepted a construct the parser cannot build.
+    UnexpectedRule,
+    /// `S0003`, a string contains an invalid escape sequence.
+    InvalidEscape,
+    /// `S0004`, the input is nested deeper than the parser allows.
+    NestingTooDeep,
+    /// `S0005`, the grammar took more steps than the parser allows.
+    CallLimit,
+}
+
 pub type Result<T> = std::result::Result<T, SDLError>;
 
+impl SyntaxErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UnexpectedToken => "S0001",
+            Self::UnexpectedRule => "S0002",
+            Self::InvalidEscape => "S0003",
+            Self::NestingTooDeep => "S0004",
+            Self::CallLimit => "S0005",
+        }
+    }
+}
+
+impl Display for SyntaxErrorCode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl Error for SDLError {}
 
 impl Display for SDLError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self.kind.as_ref() {
-            SDLErrorKind::FileNotFound(_) => {write!(f, "FileNotFound")}
-            SDLErrorKind::InvalidOperation { .. } => {write!(f, "InvalidOperation")}
+            SDLErrorKind::FileNotFound { path, position } => {
+                writeln!(f, "FileNotFound: Module `{}` is not found", path)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::InvalidOperation { info, position } => {
+                writeln!(f, "OperationError: {}", info)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
             SDLErrorKind::InvalidIndex { index, item_type, position } => {
                 writeln!(f, "IndexError: Unable to get index {} on type `{}`", index, item_type)?;
                 write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
@@ -49,12 +140,59 @@ impl Display for SDLError {
                 writeln!(f, "IteratorError: Type `{}` is not an iterable element", item_type)?;
                 write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
             }
+            SDLErrorKind::UndefinedSymbol { symbol, position } => {
+                writeln!(f, "NameError: Symbol `{}` is not defined", symbol)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::NotCallable { item_type, position } => {
+                writeln!(f, "CallError: Type `{}` is not callable", item_type)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::InvalidArgument { info, position } => {
+                writeln!(f, "ArgumentError: {}", info)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::InvalidType { info, position } => {
+                writeln!(f, "TypeError: {}", info)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::ImmutableVariable { symbol, position } => {
+                writeln!(f, "AssignError: Cannot assign twice to immutable variable `{}`", symbol)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::InvalidPattern { info, position } => {
+                writeln!(f, "PatternError: {}", info)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
             SDLErrorKind::IfLost { position } => {
                 writeln!(f, "IfLostError: If statements are not exhaustive")?;
                 write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
             }
+            SDLErrorKind::DivideByZero { position } => {
+                writeln!(f, "ZeroDivisionError: Division by zero")?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::InvalidTemplate { info, position } => {
+                writeln!(f, "TemplateError: {}", info)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::InvalidImport { info, position } => {
+                writeln!(f, "ImportError: {}", info)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::Deprecated { info, position } => {
+                writeln!(f, "DeprecationWarning: {}", info)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::RecursionLimit { limit, position } => {
+                writeln!(f, "RecursionError: Maximum call depth of {} exceeded", limit)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
+            SDLErrorKind::SyntaxError { code, info, position, .. } => {
+                writeln!(f, "SyntaxError[{}]: {}", code, info)?;
+                write!(f, "--> {}:{}",position.start.line+ 1,position.start.character + 1)
+            }
             SDLErrorKind::FormatError(_) => {write!(f, "FormatError")}
-            SDLErrorKind::LexerError { .. } => {write!(f, "LexerError")}
 
         }
     }
@@ -70,10 +208,17 @@ impl From<std::fmt::Error> for SDLError {
 }
 
 impl SDLError {
-    pub fn lexer_error(msg: impl Into<String>) -> SDLError {
+    pub fn kind(&self) -> &SDLErrorKind {
+        self.kind.as_ref()
+    }
+
+    pub fn syntax_error(code: SyntaxErrorCode, msg: impl Into<String>, expected: Vec<String>, p: Range) -> SDLError {
         Self {
-            kind: Box::new(SDLErrorKind::LexerError {
+            kind: Box::new(SDLErrorKind::SyntaxError {
+                code,
                 info: msg.into(),
+                expected,
+                position: p
             })
         }
     }
@@ -96,6 +241,60 @@ impl SDLError {
         }
     }
 
+    pub fn undefined_symbol(symbol: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::UndefinedSymbol {
+                symbol: symbol.into(),
+                position: p
+            })
+        }
+    }
+
+    pub fn not_callable(item_type: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::NotCallable {
+                item_type: item_type.into(),
+                position: p
+            })
+        }
+    }
+
+    pub fn invalid_argument(msg: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::InvalidArgument {
+                info: msg.into(),
+                position: p
+            })
+        }
+    }
+
+    pub fn invalid_type(msg: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::InvalidType {
+                info: msg.into(),
+                position: p
+            })
+        }
+    }
+
+    pub fn immutable_variable(symbol: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::ImmutableVariable {
+                symbol: symbol.into(),
+                position: p
+            })
+        }
+    }
+
+    pub fn invalid_pattern(msg: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::InvalidPattern {
+                info: msg.into(),
+                position: p
+            })
+        }
+    }
+
     pub fn if_lost(p: Range) -> SDLError {
         Self {
             kind: Box::new(SDLErrorKind::IfLost {
@@ -105,6 +304,63 @@ impl SDLError {
 
     }
 
+    pub fn divide_by_zero(p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::DivideByZero {
+                position: p
+            })
+        }
+    }
+
+    pub fn invalid_template(msg: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::InvalidTemplate {
+                info: msg.into(),
+                position: p
+            })
+        }
+    }
+
+    pub fn file_not_found(path: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::FileNotFound {
+                path: path.into(),
+                position: p
+            })
+        }
+    }
+
+    pub fn invalid_import(msg: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::InvalidImport {
+                info: msg.into(),
+                position: p
+            })
+        }
+    }
+
+    pub fn deprecated(msg: impl Into<String>, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::Deprecated {
+                info: msg.into(),
+                position: p
+            })
+        }
+    }
+
+    pub fn recursion_limit(limit: usize, p: Range) -> SDLError {
+        Self {
+            kind: Box::new(SDLErrorKind::RecursionLimit {
+                limit,
+                position: p
+            })
+        }
+    }
+
+    pub fn invalid_escape(msg: impl Into<String>, p: Range) -> SDLError {
+        Self::syntax_error(SyntaxErrorCode::InvalidEscape, msg, vec![], p)
+    }
+
     pub fn invalid_index(index: impl Into<String>,
                          item_type: impl Into<String>,
                          position: Range,) -> SDLError {
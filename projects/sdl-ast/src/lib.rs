@@ -5,5 +5,6 @@ mod traits;
 mod utils;
 
 pub use ast::{ASTKind, Template, ASTNode};
-pub use compile::{SDLContext, Variable};
-pub use errors::{Result, SDLError};
\ No newline at end of file
+pub use compile::{Annotated, FromASTNode, IntoNativeFunction, ModuleLoader, SDLContext, SDLContextConfig, TaggedString, Variable, WarningLevel};
+pub use errors::{Result, SDLError, SDLErrorKind, SyntaxErrorCode};
+pub use utils::{escape_attribute, escape_text};
\ No newline at end of file
@@ -0,0 +1,111 @@
+use super::*;
+use crate::{compile::Variable, Annotated};
+
+impl Evaluate for AnnotationStatement {
+    fn evaluate(&self, _: &mut SDLContext) -> Result<ASTNode> {
+        unreachable!()
+    }
+
+    /// Values defined by the statement are replaced by the decorated ones, a statement that defines
+    /// nothing outputs its decorated value.
+    fn evaluate_kind(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
+        if self.is_test(ctx) && !ctx.config().run_tests {
+            return Ok(ASTKind::Null);
+        }
+        let names = defined_names(&self.statement);
+        let out = self.statement.evaluate(ctx)?.unwrap_block();
+        if let ASTKind::Signal(_) = out.kind {
+            return Ok(out.kind);
+        }
+        if names.is_empty() {
+            let out = unwind!(self.decorate(None, out, ctx)?);
+            return Ok(self.run_test(out, ctx)?.kind);
+        }
+        for name in names {
+            let variable = match ctx.get_variable(&name) {
+                Some(s) => s,
+                None => continue,
+            };
+            let value = unwind!(self.decorate(Some(name.to_owned()), variable.get(), ctx)?);
+            let value = self.run_test(value, ctx)?;
+            match variable {
+                Variable::Variable(_) => ctx.declare(&name, Variable::Variable(value)),
+                _ => ctx.declare(&name, Variable::Constant(value)),
+            }
+        }
+        Ok(ASTKind::Null)
+    }
+}
+
+impl AnnotationStatement {
+    fn is_test(&self, ctx: &SDLContext) -> bool {
+        self.annotations.iter().any(|a| is_builtin_test(a, ctx))
+    }
+    /// A `#test` function runs right after it is defined.
+    fn run_test(&self, value: ASTNode, ctx: &mut SDLContext) -> Result<ASTNode> {
+        if !self.is_test(ctx) {
+            return Ok(value);
+        }
+        if let ASTKind::Function(_) | ASTKind::NativeFunction(_) = value.kind {
+            value.call(CallApply::default(), value.range)?;
+        }
+        Ok(ASTNode::null(value.range))
+    }
+    fn decorate(&self, name: Option<String>, mut value: ASTNode, ctx: &mut SDLContext) -> Result<Flow<ASTNode>> {
+        for annotation in self.annotations.iter().rev() {
+            if is_builtin_test(annotation, ctx) {
+                continue;
+            }
+            let mut arguments = match annotation.arguments.evaluate_arguments(ctx)? {
+                Ok(v) => v,
+                Err(signal) => return Ok(Err(signal)),
+            };
+            // functions in scope go first, they are called as `annotation(value, arguments)`
+            if let Some(f) = resolve_function(&annotation.name, ctx) {
+                arguments.arguments.insert(0, value);
+                value = f.call(arguments, annotation.range)?;
+                continue;
+            }
+            let handler = match ctx.get_annotation(&annotation.name()) {
+                Some(s) => s,
+                None => return Err(SDLError::undefined_symbol(annotation.name(), annotation.name.range)),
+            };
+            let target = Annotated { name: name.to_owned(), value, arguments, position: annotation.range };
+            value = handler.call(target, ctx)?;
+        }
+        Ok(Ok(value))
+    }
+}
+
+/// `#test` that is not shadowed by a function or a registered annotation.
+fn is_builtin_test(annotation: &Annotation, ctx: &SDLContext) -> bool {
+    annotation.name() == "test" && resolve_function(&annotation.name, ctx).is_none() && ctx.get_annotation("test").is_none()
+}
+
+fn resolve_function(name: &ASTNode, ctx: &SDLContext) -> Option<ASTNode> {
+    let symbol = match &name.kind {
+        ASTKind::Symbol(s) => s,
+        _ => return None,
+    };
+    ctx.resolve(symbol).filter(|f| matches!(f.kind, ASTKind::Function(_) | ASTKind::NativeFunction(_)))
+}
+
+/// Names defined by `def`, `class`, `let` and `var`.
+fn defined_names(statement: &ASTNode) -> Vec<String> {
+    match &statement.kind {
+        ASTKind::Statement(v) => v.iter().flat_map(defined_names).collect(),
+        ASTKind::FunctionDefine(e) => vec![e.name()],
+        ASTKind::ClassStatement(e) => vec![e.name()],
+        ASTKind::Assign(e) if e.kind != AssignKind::Set => pattern_names(&e.pattern),
+        ASTKind::AnnotationStatement(e) => defined_names(&e.statement),
+        _ => vec![],
+    }
+}
+
+fn pattern_names(pattern: &ASTNode) -> Vec<String> {
+    match &pattern.kind {
+        ASTKind::Symbol(s) => vec![s.name()],
+        ASTKind::List(v) => v.iter().flat_map(pattern_names).collect(),
+        _ => vec![],
+    }
+}
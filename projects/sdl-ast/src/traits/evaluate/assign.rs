@@ -0,0 +1,57 @@
+use super::*;
+use crate::{utils::get_variant_name, Variable};
+
+impl Evaluate for Assign {
+    fn evaluate(&self, _: &mut SDLContext) -> Result<ASTNode> {
+        unreachable!()
+    }
+
+    fn evaluate_kind(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
+        let value = unwind!(evaluate_value(&self.value, ctx)?);
+        bind_pattern(&self.pattern, value, self.kind, ctx)?;
+        Ok(ASTKind::Null)
+    }
+}
+
+pub(crate) fn bind_pattern(pattern: &ASTNode, value: ASTNode, kind: AssignKind, ctx: &mut SDLContext) -> Result<()> {
+    match &pattern.kind {
+        ASTKind::Symbol(s) => bind_symbol(s, value, kind, ctx),
+        ASTKind::List(names) => {
+            let items = match value.kind {
+                ASTKind::List(v) => v,
+                _ => {
+                    let msg = format!("Unable to unpack type `{}` into {} variables", get_variant_name(&value.kind), names.len());
+                    return Err(SDLError::invalid_pattern(msg, value.range));
+                }
+            };
+            if items.len() != names.len() {
+                let msg = format!("Unable to unpack {} items into {} variables", items.len(), names.len());
+                return Err(SDLError::invalid_pattern(msg, pattern.range));
+            }
+            for (name, item) in names.iter().zip(items) {
+                bind_pattern(name, item, kind, ctx)?
+            }
+            Ok(())
+        }
+        _ => {
+            let msg = format!("Unable to assign to type `{}`", get_variant_name(&pattern.kind));
+            Err(SDLError::invalid_pattern(msg, pattern.range))
+        }
+    }
+}
+
+fn bind_symbol(symbol: &Symbol, value: ASTNode, kind: AssignKind, ctx: &mut SDLContext) -> Result<()> {
+    let name = symbol.name();
+    match kind {
+        AssignKind::Let => ctx.declare(&name, Variable::Constant(value)),
+        AssignKind::Var => ctx.declare(&name, Variable::Variable(value)),
+        AssignKind::Set => match ctx.get_variable(&name) {
+            None => return Err(SDLError::undefined_symbol(name, symbol.range())),
+            Some(Variable::Constant(_)) => return Err(SDLError::immutable_variable(name, symbol.range())),
+            Some(_) => {
+                ctx.update(&name, value);
+            }
+        },
+    }
+    Ok(())
+}
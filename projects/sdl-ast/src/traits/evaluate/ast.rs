@@ -1,12 +1,34 @@
 use super::*;
+use super::expression::evaluate_spread;
+use crate::utils::ensure_stack;
 
 
 impl Evaluate for ASTNode {
     fn evaluate(&self, ctx: &mut SDLContext) -> Result<ASTNode> {
+        ensure_stack(|| self.evaluate_node(ctx))
+    }
+}
+
+impl ASTNode {
+    fn evaluate_node(&self, ctx: &mut SDLContext) -> Result<ASTNode> {
        let result = match &self.kind {
-            ASTKind::Program(v)|ASTKind::Statement(v) => {
+            ASTKind::Program(v) => {
+                let kind = match evaluate_vec_ast(v, ctx)? {
+                    ASTKind::Signal(s) => s.finish()?.kind,
+                    kind => kind,
+                };
+                ASTNode { kind, range: self.range }
+            },
+            ASTKind::Statement(v) => {
+                ASTNode {
+                    kind: evaluate_vec_ast(v, ctx)?,
+                    range: self.range
+                }.unwrap_block()
+            },
+            ASTKind::Block(v) => {
+                let mut ctx = ctx.fork();
                 ASTNode {
-                    kind: ASTKind::Block(evaluate_vec_ast(v, ctx)?),
+                    kind: evaluate_vec_ast(v, &mut ctx)?,
                     range: self.range
                 }
             },
@@ -14,13 +36,15 @@ impl Evaluate for ASTNode {
                 let out = e.kind.evaluate(ctx)?;
                 ASTNode {
                     kind: match eos {
-                        true => ASTKind::Null,
-                        false => out.kind,
+                        true if !matches!(out.kind, ASTKind::Signal(_)) => ASTKind::Null,
+                        _ => out.kind,
                     },
                     range: self.range
                 }
             }
             ASTKind::InfixExpression(inner) => inner.evaluate(ctx)?,
+            ASTKind::PrefixExpression(inner) => inner.evaluate_prefix(ctx)?,
+            ASTKind::SuffixExpression(inner) => inner.evaluate_suffix(ctx)?,
             ASTKind::StringExpression(inner) => ASTNode {
                 kind: inner.evaluate_kind(ctx)?,
                 range: self.range
@@ -33,25 +57,74 @@ impl Evaluate for ASTNode {
                     range: self.range
                 }
             },
-            ASTKind::Template(inner) => inner.evaluate(ctx)?,
+            ASTKind::ControlFlow(inner) => ASTNode {
+                kind: inner.evaluate_at(ctx, self.range)?,
+                range: self.range
+            },
+            ASTKind::Assign(inner) => {
+                ASTNode {
+                    kind: inner.evaluate_kind(ctx)?,
+                    range: self.range
+                }
+            },
+            ASTKind::FunctionDefine(inner) => {
+                ASTNode {
+                    kind: inner.evaluate_kind(ctx)?,
+                    range: self.range
+                }
+            },
+            ASTKind::ClassStatement(inner) => ASTNode {
+                kind: inner.evaluate_kind(ctx)?,
+                range: self.range
+            },
+            ASTKind::AnnotationStatement(inner) => ASTNode {
+                kind: inner.evaluate_kind(ctx)?,
+                range: self.range
+            },
+            ASTKind::ImportStatement(inner) => ASTNode {
+                kind: inner.evaluate_kind(ctx)?,
+                range: self.range
+            },
+            ASTKind::ExtendStatement(inner) => ASTNode {
+                kind: inner.evaluate_kind(ctx)?,
+                range: self.range
+            },
+            ASTKind::Template(inner) => ASTNode {
+                kind: inner.evaluate_kind(ctx)?,
+                range: self.range
+            },
             ASTKind::Symbol(inner) => inner.evaluate(ctx)?,
 
             ASTKind::CallChain(inner) => inner.evaluate(ctx)?,
 
             ASTKind::List(inner) => {
-                let list = inner.iter().flat_map(|e| e.evaluate(ctx)).collect();
+                let list = unwind!(evaluate_spread(inner, ctx)?);
                 ASTNode {
                     kind: ASTKind::List(list),
                     range: self.range
                 }
             },
 
+            ASTKind::Dict(inner) => {
+                let mut dict = IndexMap::with_capacity(inner.len());
+                for (k, v) in inner.iter() {
+                    dict.insert(k.to_owned(), unwind!(evaluate_value(v, ctx)?));
+                }
+                ASTNode::dict(dict, self.range)
+            },
+
             ASTKind::Null |
             ASTKind::Boolean(_) |
             ASTKind::HTMLText(_) |
             ASTKind::String(_) |
             ASTKind::Integer(_) |
-            ASTKind::Decimal(_) => self.to_owned(),
+            ASTKind::Decimal(_) |
+            ASTKind::HTMLElement(_) |
+            ASTKind::Function(_) |
+            ASTKind::NativeFunction(_) |
+            ASTKind::Generator(_) |
+            ASTKind::Class(_) |
+            ASTKind::Object(_) => self.to_owned(),
 
             _ => unimplemented!("ASTKind::{:?} => {{}}", self.kind),
         };
@@ -69,13 +142,20 @@ impl Evaluate for ASTKind {
 }
 
 
-fn evaluate_vec_ast(v: &[ASTNode], ctx: &mut SDLContext) -> Result<Vec<ASTNode>> {
+/// Evaluate statements in order, a signal stops the rest and carries the values collected before it.
+fn evaluate_vec_ast(v: &[ASTNode], ctx: &mut SDLContext) -> Result<ASTKind> {
     let mut collected = Vec::with_capacity(v.len());
     for e in v {
         let out = e.evaluate(ctx)?;
-        if !out.kind.is_null() {
-            collected.push(out)
+        match out.kind {
+            ASTKind::Null => (),
+            ASTKind::Signal(mut s) => {
+                collected.append(&mut s.output);
+                s.output = collected;
+                return Ok(ASTKind::Signal(s));
+            }
+            _ => collected.push(out),
         }
     }
-    Ok(collected)
+    Ok(ASTKind::Block(collected))
 }
@@ -0,0 +1,101 @@
+use super::*;
+use std::rc::Rc;
+
+impl Evaluate for ClassStatement {
+    fn evaluate(&self, _: &mut SDLContext) -> Result<ASTNode> {
+        unreachable!()
+    }
+
+    /// A bare symbol declares a field without default, functions defined in the body are methods and other
+    /// definitions are fields with default.
+    fn evaluate_kind(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
+        let mut scope = ctx.fork();
+        let mut fields = IndexMap::new();
+        let mut methods = IndexMap::new();
+        for statement in class_statements(&self.body) {
+            if let Some(field) = bare_symbol(&statement) {
+                fields.insert(field, None);
+                continue;
+            }
+            let defined = scope.definitions().len();
+            statement.evaluate(&mut scope)?;
+            for (name, value) in scope.definitions().into_iter().skip(defined) {
+                match value.kind {
+                    ASTKind::Function(_) | ASTKind::NativeFunction(_) => {
+                        methods.insert(name, value);
+                    }
+                    _ => {
+                        fields.insert(name, Some(value));
+                    }
+                }
+            }
+        }
+        let class = Class { name: self.name(), fields, methods };
+        ctx.insert(&self.name(), ASTNode { kind: ASTKind::Class(Rc::new(class)), range: self.name.range });
+        Ok(ASTKind::Null)
+    }
+}
+
+/// Statements of the body, where `a; b` are separated.
+fn class_statements(body: &ASTNode) -> Vec<ASTNode> {
+    let mut out = vec![];
+    for statement in body.as_vec() {
+        match statement.kind {
+            ASTKind::Statement(v) => out.extend(v),
+            _ => out.push(statement),
+        }
+    }
+    out
+}
+
+fn bare_symbol(statement: &ASTNode) -> Option<String> {
+    match &statement.kind {
+        ASTKind::Expression(e, _) => match &e.kind {
+            ASTKind::Symbol(s) if s.namespace().is_empty() => Some(s.name()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl Class {
+    /// Positional arguments fill fields in the order of declaration, keyword arguments fill fields by name.
+    pub fn instantiate(self: &Rc<Self>, args: CallApply, position: Range) -> Result<ASTNode> {
+        if args.arguments.len() > self.fields.len() {
+            let msg = format!("Class `{}` takes {} fields but {} were given", self.name, self.fields.len(), args.arguments.len());
+            return Err(SDLError::invalid_argument(msg, position));
+        }
+        let mut filled: Vec<Option<ASTNode>> = vec![None; self.fields.len()];
+        for (i, v) in args.arguments.into_iter().enumerate() {
+            filled[i] = Some(v)
+        }
+        for (k, v) in args.keywords {
+            let key = k.as_string();
+            match self.fields.get_index_of(&key) {
+                None => {
+                    let msg = format!("Class `{}` has no field `{}`", self.name, key);
+                    return Err(SDLError::invalid_argument(msg, k.range));
+                }
+                Some(i) if filled[i].is_some() => {
+                    let msg = format!("Class `{}` got multiple values for field `{}`", self.name, key);
+                    return Err(SDLError::invalid_argument(msg, k.range));
+                }
+                Some(i) => filled[i] = Some(v),
+            }
+        }
+        let mut fields = IndexMap::with_capacity(self.fields.len());
+        for ((name, default), value) in self.fields.iter().zip(filled) {
+            let value = match (value, default) {
+                (Some(v), _) => v,
+                (None, Some(default)) => default.to_owned(),
+                (None, None) => {
+                    let msg = format!("Class `{}` missing required field `{}`", self.name, name);
+                    return Err(SDLError::invalid_argument(msg, position));
+                }
+            };
+            fields.insert(name.to_owned(), value);
+        }
+        let object = Object { class: self.to_owned(), fields };
+        Ok(ASTNode { kind: ASTKind::Object(Box::new(object)), range: position })
+    }
+}
@@ -1,5 +1,10 @@
 use super::*;
+use super::assign::bind_pattern;
 use crate::utils::get_variant_name;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
 impl Evaluate for ForInLoop {
     fn evaluate(&self, _: &mut SDLContext) -> Result<ASTNode> {
@@ -7,52 +12,189 @@ impl Evaluate for ForInLoop {
     }
 
     fn evaluate_kind(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
-        let terms = self.terms.evaluate(ctx)?;
-        let symbol = match &self.pattern.kind {
-            ASTKind::Symbol(s) => s.name(),
-            _ => unreachable!(),
-        };
+        let terms = unwind!(evaluate_value(&self.terms, ctx)?);
         let items = match terms.kind {
             ASTKind::List(v) => v,
+            // `for (key, value) in dict`
+            ASTKind::Dict(v) => v.into_iter().map(|(k, v)| ASTNode::from(vec![ASTNode::from(k), v])).collect(),
             ASTKind::String(v) => {
                 // FIXME: avoid collect
                 v.chars().map(|e| ASTNode::from(e)).collect()
             }
+            ASTKind::Generator(g) => return self.evaluate_generator(*g, ctx),
             _ => return Err(SDLError::invalid_iterator( get_variant_name(terms.kind),terms.range)),
         };
+        // the guard runs just before the body of its item, `loop.last` and `loop.length` describe the whole sequence
+        let length = items.len();
         let mut out = vec![];
-        for i in items {
-            let mut ctx = ctx.fork();
-            ctx.insert(&symbol, i);
-            let result = self.block.evaluate(&mut ctx)?;
-            out.push(result)
+        let mut index = 0;
+        for (i, item) in items.into_iter().enumerate() {
+            let mut scope = ctx.fork();
+            bind_pattern(&self.pattern, item, AssignKind::Let, &mut scope)?;
+            if !self.check_guard(&mut scope)? {
+                continue;
+            }
+            scope.insert("loop", loop_info(index, Some((i + 1 == length, length))));
+            index += 1;
+            let result = self.block.evaluate(&mut scope)?;
+            if let Some(signal) = push_iteration(&mut out, result) {
+                return Ok(end_loop(signal, out));
+            }
+        }
+        if index == 0 {
+            return self.evaluate_else(ctx);
         }
         Ok(ASTKind::Block(out))
     }
 }
 
+impl ForInLoop {
+    fn check_guard(&self, scope: &mut SDLContext) -> Result<bool> {
+        match &self.guard {
+            Some(guard) => Ok(guard.evaluate(scope)?.kind.is_truthy()),
+            None => Ok(true),
+        }
+    }
+    /// The `else` block runs when no item is iterated.
+    fn evaluate_else(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
+        match &self.for_else {
+            Some(block) => block.evaluate_kind(ctx),
+            None => Ok(ASTKind::Block(vec![])),
+        }
+    }
+    /// The body of the generator drives the loop, each `yield` runs one iteration.
+    ///
+    /// Items are not known in advance, so `loop.last` and `loop.length` are not available.
+    fn evaluate_generator(&self, generator: Generator, ctx: &mut SDLContext) -> Result<ASTKind> {
+        let out = Rc::new(RefCell::new(vec![]));
+        let count = Rc::new(Cell::new(0));
+        let stop: Rc<RefCell<Option<Box<Signal>>>> = Default::default();
+        let handler = {
+            let (this, outer, out, count, stop) = (self.to_owned(), ctx.to_owned(), out.clone(), count.clone(), stop.clone());
+            move |item: ASTNode| {
+                let mut scope = outer.fork();
+                bind_pattern(&this.pattern, item, AssignKind::Let, &mut scope)?;
+                if !this.check_guard(&mut scope)? {
+                    return Ok(None);
+                }
+                scope.insert("loop", loop_info(count.get(), None));
+                count.set(count.get() + 1);
+                let result = this.block.evaluate(&mut scope)?;
+                let signal = match push_iteration(&mut out.borrow_mut(), result) {
+                    Some(s) => s,
+                    None => return Ok(None),
+                };
+                let range = signal.range;
+                *stop.borrow_mut() = Some(signal);
+                // unwind the generator as a `return`, so loops inside the generator will not take it
+                Ok(Some(Signal { kind: ControlKind::Return, value: None, output: vec![], range }))
+            }
+        };
+        let mut scope = generator.scope.fork();
+        scope.set_yield_handler(handler);
+        let depth = scope.enter_call(self.terms.range)?;
+        generator.function.define.body.evaluate(&mut scope)?;
+        drop(depth);
+        if count.get() == 0 {
+            return self.evaluate_else(ctx);
+        }
+        let out = out.take();
+        Ok(match stop.take() {
+            Some(signal) => end_loop(signal, out),
+            None => ASTKind::Block(out),
+        })
+    }
+}
+
+/// The `loop` variable of an iteration, `index` starts from 1 as `.1` does.
+///
+/// `index` and `first` count the iterations that ran, `last` and `length` count the items before the guard.
+fn loop_info(index: usize, length: Option<(bool, usize)>) -> IndexMap<String, ASTNode> {
+    let mut info = IndexMap::new();
+    info.insert("index".to_string(), ASTNode::from(index + 1));
+    info.insert("index0".to_string(), ASTNode::from(index));
+    info.insert("first".to_string(), ASTNode::from(index == 0));
+    if let Some((last, length)) = length {
+        info.insert("last".to_string(), ASTNode::from(last));
+        info.insert("length".to_string(), ASTNode::from(length));
+    }
+    info
+}
+
+/// Push the result of one iteration, returns the signal if the iteration was interrupted.
+fn push_iteration(out: &mut Vec<ASTNode>, result: ASTNode) -> Option<Box<Signal>> {
+    match result.kind {
+        ASTKind::Signal(mut s) => {
+            out.push(ASTNode::block(std::mem::take(&mut s.output), result.range));
+            Some(s)
+        }
+        _ => {
+            out.push(result);
+            None
+        }
+    }
+}
+
+/// `break` ends the loop with what has been collected, `return` goes on with it.
+fn end_loop(mut signal: Box<Signal>, out: Vec<ASTNode>) -> ASTKind {
+    match signal.kind {
+        ControlKind::Break => ASTKind::Block(out),
+        _ => {
+            signal.output = out;
+            ASTKind::Signal(signal)
+        }
+    }
+}
+
+impl ControlFlow {
+    pub(super) fn evaluate_at(&self, ctx: &mut SDLContext, range: Range) -> Result<ASTKind> {
+        let value = match &self.value {
+            Some(v) if matches!(self.kind, ControlKind::Break | ControlKind::Pass) => {
+                let msg = format!("`{}` does not take a value", self.kind.keyword());
+                return Err(SDLError::invalid_operation(msg, v.range));
+            }
+            Some(v) => Some(unwind!(evaluate_value(v, ctx)?)),
+            None => None,
+        };
+        match self.kind {
+            ControlKind::Pass => Ok(ASTKind::Null),
+            ControlKind::Yield => match ctx.yield_value(value.unwrap_or_else(|| ASTNode::null(range)), range)? {
+                Some(signal) => Ok(ASTKind::Signal(Box::new(signal))),
+                None => Ok(ASTKind::Null),
+            },
+            kind => Ok(ASTKind::Signal(Box::new(Signal { kind, value, output: vec![], range }))),
+        }
+    }
+}
+
+impl Signal {
+    /// Take the signal at the boundary of a function, `return` gives its value or the collected output.
+    pub(super) fn finish(self) -> Result<ASTNode> {
+        match self.kind {
+            ControlKind::Return => match self.value {
+                Some(v) => Ok(v),
+                None => Ok(ASTNode::block(self.output, self.range).unwrap_block()),
+            },
+            _ => {
+                let msg = format!("`{}` outside of a loop", self.kind.keyword());
+                Err(SDLError::invalid_operation(msg, self.range))
+            }
+        }
+    }
+}
+
 impl Evaluate for IfElseChain {
     fn evaluate(&self, ctx: &mut SDLContext) -> Result<ASTNode> {
         for (cds, act) in &self.pairs {
-            match cds.evaluate(ctx)?.kind {
-                ASTKind::Boolean(true) => return Ok(act.evaluate(ctx)?),
-                _ => continue,
+            if unwind!(evaluate_value(cds, ctx)?).kind.is_truthy() {
+                return act.evaluate(ctx);
             }
         }
         match &self.cover {
-            Some(last) => Ok(last.evaluate(ctx)?),
+            Some(last) => last.evaluate(ctx),
             None => {
-                let err = SDLError::if_lost(self.range);
-                match ctx.config().is_debug {
-                    true => Err(err),
-                    false => {
-                        println!("{}", err);
-                        Ok(ASTNode {
-                            kind: ASTKind::Null,
-                            range: self.range
-                        })
-                    },
-                }
+                ctx.warn(SDLError::if_lost(self.range))?;
+                Ok(ASTNode::null(self.range))
             }
         }
     }
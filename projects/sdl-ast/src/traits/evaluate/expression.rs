@@ -1,28 +1,174 @@
 use super::*;
-
-
+use crate::utils::get_variant_name;
+use std::cmp::Ordering;
 
 impl Evaluate for InfixExpression {
     fn evaluate(&self, ctx: &mut SDLContext) -> Result<ASTNode> {
-        match self.op.as_string().as_str() {
-            "+" => self.lhs.evaluate(ctx)? + self.rhs.evaluate(ctx)?,
-            "++" => self.lhs.evaluate(ctx)?.concat(self.rhs.evaluate(ctx)?),
-            "-" => self.lhs.evaluate(ctx)? - self.rhs.evaluate(ctx)?,
-            "*" => self.lhs.evaluate(ctx)? * self.rhs.evaluate(ctx)?,
-            "/" => self.lhs.evaluate(ctx)? / self.rhs.evaluate(ctx)?,
-            // "==" | "is" => Ok(Value::Boolean(self.lhs.kind == self.rhs.kind)),
-            // "!=" | "isnot" => Ok(Value::Boolean(self.lhs.kind != self.rhs.kind)),
-            _ => unimplemented!("Operation: {}", self.op.as_string().as_str()),
+        let op = self.op.as_string();
+        // short-circuit, the rhs is only evaluated when needed
+        match op.as_str() {
+            "and" | "&&" | "∧" => {
+                let lhs = unwind!(evaluate_value(&self.lhs, ctx)?);
+                let out = lhs.kind.is_truthy() && unwind!(evaluate_value(&self.rhs, ctx)?).kind.is_truthy();
+                return Ok(ASTNode::boolean(out, self.op.range));
+            }
+            "or" | "||" | "∨" => {
+                let lhs = unwind!(evaluate_value(&self.lhs, ctx)?);
+                let out = lhs.kind.is_truthy() || unwind!(evaluate_value(&self.rhs, ctx)?).kind.is_truthy();
+                return Ok(ASTNode::boolean(out, self.op.range));
+            }
+            _ => (),
         }
+        let lhs = unwind!(evaluate_value(&self.lhs, ctx)?);
+        let rhs = unwind!(evaluate_value(&self.rhs, ctx)?);
+        let p = self.op.range;
+        let out = match op.as_str() {
+            "+" => return lhs.add(rhs, p),
+            "++" => return lhs.concat(rhs, p),
+            "-" => return lhs.subtract(rhs, p),
+            "*" => return lhs.multiply(rhs, p),
+            "/" => return lhs.divide(rhs, ctx.config().precision, p),
+            "%" => return lhs.modulo(rhs, p),
+            "^" => return lhs.power(rhs, ctx.config().precision, p),
+            "==" | "is" => lhs.kind.equals(&rhs.kind),
+            "!=" | "isnot" => !lhs.kind.equals(&rhs.kind),
+            "<" => lhs.compare(&rhs, p)? == Ordering::Less,
+            "<=" => lhs.compare(&rhs, p)? != Ordering::Greater,
+            ">" => lhs.compare(&rhs, p)? == Ordering::Greater,
+            ">=" => lhs.compare(&rhs, p)? != Ordering::Less,
+            "in" => rhs.contains(&lhs, p)?,
+            "notin" => !rhs.contains(&lhs, p)?,
+            _ => return Err(SDLError::invalid_operation(format!("Unsupported operator `{}`", op), p)),
+        };
+        Ok(ASTNode::boolean(out, p))
+    }
+}
+
+impl UnaryExpression {
+    /// `-x`, `+x`, `√x`, `!x` or `¬x`, the spread `*x` is expanded by the enclosing list or call.
+    pub(crate) fn evaluate_prefix(&self, ctx: &mut SDLContext) -> Result<ASTNode> {
+        let op = self.op.as_string();
+        let p = self.op.range;
+        if op == "*" {
+            return Err(SDLError::invalid_operation("Spread `*` is only allowed inside lists and calls", p));
+        }
+        let base = unwind!(evaluate_value(&self.base, ctx)?);
+        if op == "√" {
+            return base.square_root(ctx.config().precision, p);
+        }
+        let kind = match (op.as_str(), base.kind) {
+            ("!", v) | ("¬", v) => ASTKind::Boolean(v.is_falsy()),
+            ("+", v @ ASTKind::Integer(_)) | ("+", v @ ASTKind::Decimal(_)) => v,
+            ("-", ASTKind::Integer(v)) => ASTKind::Integer(Box::new(-*v)),
+            ("-", ASTKind::Decimal(v)) => ASTKind::Decimal(Box::new(-*v)),
+            (_, v) => {
+                let info = format!("Cannot apply prefix `{}` to type `{}`", op, get_variant_name(&v));
+                return Err(SDLError::invalid_operation(info, p));
+            }
+        };
+        Ok(ASTNode { kind, range: base.range })
+    }
+    /// `x?` turns an error or `null` into `null` and stops the rest of the call chain,
+    /// `x!` asserts that `x` is not `null`.
+    pub(crate) fn evaluate_suffix(&self, ctx: &mut SDLContext) -> Result<ASTNode> {
+        let op = self.op.as_string();
+        let p = self.op.range;
+        match op.as_str() {
+            "?" => Ok(self.base.evaluate(ctx).unwrap_or_else(|_| ASTNode::null(self.base.range))),
+            "!" => {
+                let base = unwind!(evaluate_value(&self.base, ctx)?);
+                match base.kind.is_null() {
+                    true => Err(SDLError::invalid_operation("Expected a value but found `null`", p)),
+                    false => Ok(base),
+                }
+            }
+            _ => Err(SDLError::invalid_operation(format!("Unsupported operator `{}`", op), p)),
+        }
+    }
+    fn is_try(&self) -> bool {
+        self.op.as_string() == "?"
+    }
+}
+
+/// Evaluate the items of a list or the arguments of a call, expanding every `*list` in place.
+pub(super) fn evaluate_spread(items: &[ASTNode], ctx: &mut SDLContext) -> Result<Flow<Vec<ASTNode>>> {
+    let mut out = Vec::with_capacity(items.len());
+    for item in items {
+        let spread = match &item.kind {
+            ASTKind::PrefixExpression(e) if e.op.as_string() == "*" => Some(e),
+            _ => None,
+        };
+        let value = match evaluate_value(spread.map_or(item, |e| &e.base), ctx)? {
+            Ok(v) => v,
+            Err(signal) => return Ok(Err(signal)),
+        };
+        match spread {
+            Some(e) => match value {
+                ASTNode { kind: ASTKind::List(v), .. } => out.extend(v),
+                v => {
+                    let info = format!("Spread `*` expects a `List`, found `{}`", get_variant_name(&v.kind));
+                    return Err(SDLError::invalid_operation(info, e.op.range));
+                }
+            },
+            None => out.push(value),
+        }
+    }
+    Ok(Ok(out))
+}
+
+impl SliceIndex {
+    fn evaluate_index(&self, ctx: &mut SDLContext) -> Result<Flow<SliceIndex>> {
+        let (start, end, step) = match self {
+            SliceIndex::Index(e) => return Ok(evaluate_value(e, ctx)?.map(SliceIndex::Index)),
+            SliceIndex::Range { start, end, step } => (start, end, step),
+        };
+        let mut bounds = [None, None, None];
+        for (bound, e) in bounds.iter_mut().zip([start, end, step].iter()) {
+            if let Some(e) = e {
+                match evaluate_value(e, ctx)? {
+                    Ok(v) => *bound = Some(v),
+                    Err(signal) => return Ok(Err(signal)),
+                }
+            }
+        }
+        let [start, end, step] = bounds;
+        Ok(Ok(SliceIndex::Range { start, end, step }))
     }
 }
 
 impl Evaluate for CallChain {
     fn evaluate(&self, ctx: &mut SDLContext) -> Result<ASTNode> {
-        let mut base = self.base.evaluate(ctx)?;
+        let mut base = unwind!(evaluate_value(&self.base, ctx)?);
+        if let ASTKind::SuffixExpression(e) = &self.base.kind {
+            if e.is_try() && base.kind.is_null() {
+                return Ok(base);
+            }
+        }
         for i in &self.chain {
             base = match &i.kind {
                 ASTKind::CallIndex(n) => base.get_index(n.as_ref(), i.range)?,
+                ASTKind::CallApply(apply) => base.call(unwind!(apply.evaluate_arguments(ctx)?), i.range)?,
+                ASTKind::CallDot(dot) => match &dot.apply {
+                    Some(ASTNode { kind: ASTKind::CallApply(apply), .. }) => {
+                        let args = unwind!(apply.evaluate_arguments(ctx)?);
+                        base.call_method(&dot.symbol, args, ctx, i.range)?
+                    }
+                    _ => match &base.kind {
+                        ASTKind::Dict(_) => base.get_key(&dot.symbol.as_string()).unwrap_or_else(|| ASTNode::null(i.range)),
+                        ASTKind::Object(_) => match base.get_key(&dot.symbol.as_string()) {
+                            Some(s) => s,
+                            None => return Err(SDLError::invalid_index(dot.symbol.as_string(), base.get_type().as_string(), i.range)),
+                        },
+                        _ => return Err(SDLError::invalid_index(dot.symbol.as_string(), get_variant_name(&base.kind), i.range)),
+                    },
+                },
+                ASTKind::CallSlice(slice) => {
+                    let mut indexes = Vec::with_capacity(slice.indexes.len());
+                    for index in &slice.indexes {
+                        indexes.push(unwind!(index.evaluate_index(ctx)?))
+                    }
+                    base.get_slice(&indexes, i.range)?
+                }
                 _ => unimplemented!("ASTKind::{:?} => {{}}", i.kind),
             }
         }
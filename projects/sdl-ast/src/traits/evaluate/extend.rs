@@ -0,0 +1,75 @@
+use super::*;
+use super::primitive::{call_component, evaluate_children};
+use crate::{compile::library::has_builtin_method, utils::get_variant_name};
+
+/// Types that can be extended by name, as reported by `.0`.
+static BUILTIN_TYPES: &[&str] = &["Null", "Boolean", "String", "HTMLText", "Integer", "Decimal", "List", "Dict", "HTMLElement"];
+
+impl Evaluate for ExtendStatement {
+    fn evaluate(&self, _: &mut SDLContext) -> Result<ASTNode> {
+        unreachable!()
+    }
+
+    fn evaluate_kind(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
+        if let Some(name) = self.builtin_type(ctx) {
+            return self.evaluate_methods(&name, ctx);
+        }
+        let target = self.target.evaluate(ctx)?;
+        match &target.kind {
+            ASTKind::Function(_) | ASTKind::NativeFunction(_) => Ok(self.evaluate_layout(&target, ctx)?.kind),
+            ASTKind::Class(class) => self.evaluate_methods(&class.name, ctx),
+            _ => Err(SDLError::not_callable(get_variant_name(&target.kind), self.target.range)),
+        }
+    }
+}
+
+impl ExtendStatement {
+    /// A builtin type name that is not shadowed by a definition, eg: `extend String with { ... }`.
+    fn builtin_type(&self, ctx: &SDLContext) -> Option<String> {
+        let symbol = match &self.target.kind {
+            ASTKind::Symbol(s) if s.namespace().is_empty() => s,
+            _ => return None,
+        };
+        let name = symbol.name();
+        match BUILTIN_TYPES.contains(&name.as_str()) && ctx.resolve(symbol).is_none() {
+            true => Some(name),
+            false => None,
+        }
+    }
+    /// Functions defined in the block become methods of the type.
+    fn evaluate_methods(&self, type_name: &str, ctx: &mut SDLContext) -> Result<ASTKind> {
+        let mut scope = ctx.fork();
+        for statement in self.body.as_vec() {
+            statement.evaluate(&mut scope)?;
+        }
+        for (name, value) in scope.definitions() {
+            match value.kind {
+                ASTKind::Function(_) | ASTKind::NativeFunction(_) if has_builtin_method(type_name, &name) => {
+                    // builtin methods go first, the extension could never be called
+                    let msg = format!("Method `{}.{}` is builtin and cannot be extended", type_name, name);
+                    return Err(SDLError::invalid_type(msg, value.range));
+                }
+                ASTKind::Function(_) | ASTKind::NativeFunction(_) => ctx.extend_type(type_name, &name, value),
+                _ => {
+                    let msg = format!("Extension of `{}` expected methods, found `{}` `{}`", type_name, get_variant_name(&value.kind), name);
+                    return Err(SDLError::invalid_type(msg, value.range));
+                }
+            }
+        }
+        Ok(ASTKind::Null)
+    }
+    /// Definitions in the block are passed to the layout as keyword arguments, so regions that are not
+    /// overridden fall back to the defaults of the layout, names starting with `_` are helpers of the block.
+    fn evaluate_layout(&self, layout: &ASTNode, ctx: &mut SDLContext) -> Result<ASTNode> {
+        let mut scope = ctx.fork();
+        let mut children = HTMLElement::fragment();
+        if let Some(signal) = evaluate_children(&mut children, &self.body.as_vec(), &mut scope)? {
+            return Ok(ASTNode { kind: signal, range: self.target.range });
+        }
+        let mut keywords = vec![];
+        for (name, value) in scope.definitions().into_iter().filter(|(k, _)| !k.starts_with('_')) {
+            keywords.push((ASTNode::string(name, value.range), value));
+        }
+        call_component(layout, keywords, children, ctx, self.target.range)
+    }
+}
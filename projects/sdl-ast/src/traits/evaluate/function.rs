@@ -0,0 +1,125 @@
+use super::*;
+use super::expression::evaluate_spread;
+use crate::{
+    compile::library::{builtin_method, MethodCall},
+    utils::get_variant_name,
+};
+
+impl Evaluate for FunctionDefine {
+    fn evaluate(&self, _: &mut SDLContext) -> Result<ASTNode> {
+        unreachable!()
+    }
+
+    fn evaluate_kind(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
+        // the function shares the scope it is defined in, so it can call itself recursively
+        let function = Function::new(self.to_owned(), ctx.to_owned());
+        ctx.insert(&self.name(), ASTNode { kind: ASTKind::Function(Box::new(function)), range: self.name.range });
+        Ok(ASTKind::Null)
+    }
+}
+
+impl CallApply {
+    pub(super) fn evaluate_arguments(&self, ctx: &mut SDLContext) -> Result<Flow<CallApply>> {
+        let arguments = match evaluate_spread(&self.arguments, ctx)? {
+            Ok(v) => v,
+            Err(signal) => return Ok(Err(signal)),
+        };
+        let mut keywords = Vec::with_capacity(self.keywords.len());
+        for (k, v) in &self.keywords {
+            match evaluate_value(v, ctx)? {
+                Ok(v) => keywords.push((k.to_owned(), v)),
+                Err(signal) => return Ok(Err(signal)),
+            }
+        }
+        Ok(Ok(CallApply { arguments, keywords }))
+    }
+}
+
+impl ASTNode {
+    /// Call this value with evaluated arguments, `position` is the range of the call site.
+    pub fn call(&self, args: CallApply, position: Range) -> Result<ASTNode> {
+        match &self.kind {
+            ASTKind::Function(f) => f.call(args, position),
+            ASTKind::NativeFunction(f) => f.call(args, position),
+            ASTKind::Class(c) => c.instantiate(args, position),
+            _ => Err(SDLError::not_callable(get_variant_name(&self.kind), position)),
+        }
+    }
+}
+
+impl ASTNode {
+    /// Call `self.method(args)`, builtin methods of the receiver's type go first, then methods of its class
+    /// and methods added by `extend`, otherwise it is the same as `method(self, args)`.
+    pub fn call_method(self, method: &ASTNode, mut args: CallApply, ctx: &mut SDLContext, position: Range) -> Result<ASTNode> {
+        let name = method.as_string();
+        if let Some(f) = builtin_method(&self.kind, &name) {
+            return f(MethodCall::new(self, &name, args, position)?);
+        }
+        let found = match &self.kind {
+            ASTKind::Object(o) => o.class.methods.get(&name).cloned(),
+            _ => None,
+        };
+        let found = found.or_else(|| ctx.get_extension(&self.get_type().as_string(), &name));
+        args.arguments.insert(0, self);
+        match found {
+            Some(f) => f.call(args, position),
+            None => method.evaluate(ctx)?.call(args, position),
+        }
+    }
+}
+
+impl Function {
+    /// Calling a generator only binds the arguments, the body runs when it is iterated.
+    pub fn call(&self, args: CallApply, position: Range) -> Result<ASTNode> {
+        let mut scope = self.bind(args, position)?;
+        if self.define.is_generator() {
+            let generator = Generator { function: self.to_owned(), scope };
+            return Ok(ASTNode { kind: ASTKind::Generator(Box::new(generator)), range: position });
+        }
+        let _depth = scope.enter_call(position)?;
+        let out = match self.define.body.evaluate(&mut scope)?.unwrap_block() {
+            ASTNode { kind: ASTKind::Signal(s), .. } => s.finish()?,
+            out => out,
+        };
+        Ok(ASTNode { kind: out.kind, range: position })
+    }
+    /// Create the scope of a call with the arguments bound to parameters.
+    pub(super) fn bind(&self, args: CallApply, position: Range) -> Result<SDLContext> {
+        let names = self.define.parameter_names();
+        if args.arguments.len() > names.len() {
+            let msg = format!("Function `{}` takes {} arguments but {} were given", self.name(), names.len(), args.arguments.len());
+            return Err(SDLError::invalid_argument(msg, position));
+        }
+        let mut filled = vec![None; names.len()];
+        for (i, v) in args.arguments.into_iter().enumerate() {
+            filled[i] = Some(v)
+        }
+        for (k, v) in args.keywords {
+            let key = k.as_string();
+            match names.iter().position(|name| name == &key) {
+                None => {
+                    let msg = format!("Function `{}` got an unexpected keyword argument `{}`", self.name(), key);
+                    return Err(SDLError::invalid_argument(msg, k.range));
+                }
+                Some(i) if filled[i].is_some() => {
+                    let msg = format!("Function `{}` got multiple values for argument `{}`", self.name(), key);
+                    return Err(SDLError::invalid_argument(msg, k.range));
+                }
+                Some(i) => filled[i] = Some(v),
+            }
+        }
+        let mut scope = self.env.fork();
+        for ((name, default), value) in self.define.parameters.iter().zip(filled) {
+            let value = match (value, default) {
+                (Some(v), _) => v,
+                (None, Some(default)) => default.evaluate(&mut scope)?,
+                (None, None) => {
+                    let msg = format!("Function `{}` missing required argument `{}`", self.name(), name.as_string());
+                    return Err(SDLError::invalid_argument(msg, position));
+                }
+            };
+            scope.insert(&name.as_string(), value);
+        }
+        Ok(scope)
+    }
+}
@@ -0,0 +1,43 @@
+use super::*;
+
+impl Evaluate for ImportStatement {
+    fn evaluate(&self, _: &mut SDLContext) -> Result<ASTNode> {
+        unreachable!()
+    }
+
+    fn evaluate_kind(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
+        for item in &self.items {
+            item.import(ctx)?
+        }
+        Ok(ASTKind::Null)
+    }
+}
+
+impl ImportItem {
+    /// A path is a module if the loader finds it, otherwise `a::b` imports the member `b` of the module `a`.
+    fn import(&self, ctx: &mut SDLContext) -> Result<()> {
+        if let Some(module) = ctx.load_module(&self.path, self.range)? {
+            match self.glob {
+                true => {
+                    for (name, value) in module.exports() {
+                        ctx.import(&name, value)
+                    }
+                }
+                false => ctx.insert_module(&self.name(), module),
+            }
+            return Ok(());
+        }
+        if let (false, Some((parent, member))) = (self.glob, self.path.split_member()) {
+            if let Some(module) = ctx.load_module(&parent, self.range)? {
+                let value = module.exports().into_iter().find(|(name, _)| name == &member);
+                match (value, module.get_module(&member)) {
+                    (Some((_, v)), _) => ctx.import(&self.name(), v),
+                    (None, Some(m)) => ctx.insert_module(&self.name(), m),
+                    (None, None) => return Err(SDLError::undefined_symbol(format!("{:?}", self.path), self.range)),
+                }
+                return Ok(());
+            }
+        }
+        Err(SDLError::file_not_found(format!("{:?}", self.path), self.range))
+    }
+}
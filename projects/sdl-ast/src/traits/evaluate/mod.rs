@@ -1,8 +1,27 @@
 use crate::{ast::*, Result, SDLError, SDLContext};
 
+/// A value, or a signal (`return`, `break`, ...) raised while evaluating it, which unwinds the enclosing evaluation.
+type Flow<T> = std::result::Result<T, Box<Signal>>;
+
+/// Unwrap a [`Flow`], a signal is returned from the enclosing evaluation at once.
+macro_rules! unwind {
+    ($flow:expr) => {
+        match $flow {
+            Ok(v) => v,
+            Err(signal) => return Ok(ASTKind::Signal(signal).into()),
+        }
+    };
+}
+
+mod annotation;
+mod assign;
 mod ast;
+mod class;
 mod control;
 mod expression;
+mod extend;
+mod function;
+mod import;
 mod primitive;
 
 pub trait Evaluate {
@@ -12,7 +31,11 @@ pub trait Evaluate {
     }
 }
 
-pub trait Concat<Rhs = Self> {
-    type Output;
-    fn concat(self, rhs: Rhs) -> Self::Output;
-}
\ No newline at end of file
+/// Evaluate an expression whose value is used, eg: an operand, an item or an argument.
+fn evaluate_value(node: &ASTNode, ctx: &mut SDLContext) -> Result<Flow<ASTNode>> {
+    let out = node.evaluate(ctx)?;
+    match out.kind {
+        ASTKind::Signal(signal) => Ok(Err(signal)),
+        _ => Ok(Ok(out)),
+    }
+}
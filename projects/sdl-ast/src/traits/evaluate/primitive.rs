@@ -1,44 +1,173 @@
 use super::*;
-use crate::{traits::Render};
-use std::collections::BTreeSet;
-
-#[rustfmt::skip]
-pub static VOID_TAGS: &[&str; 16] = &[
-    "img", "hr", "br", "input", "link", "meta", "area", "base", "col", "wbr",
-    "command", "embed", "keygen", "param", "source", "track"
-];
+use crate::{traits::Render, utils::get_variant_name, TaggedString};
 
 impl Evaluate for Template {
-    fn evaluate(&self, ctx: &mut SDLContext) -> Result<ASTNode> {
+    fn evaluate(&self, _: &mut SDLContext) -> Result<ASTNode> {
+        unreachable!()
+    }
+
+    fn evaluate_kind(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
         let tag = match &self.tag {
-            None => unimplemented!(),
-            Some(s) => s.as_string(),
+            // `<>`, `<\>` and `<|>`
+            None => {
+                let mut html = HTMLElement::fragment();
+                if let Some(signal) = evaluate_children(&mut html, &self.children, ctx)? {
+                    return Ok(signal);
+                }
+                return Ok(ASTKind::HTMLElement(Box::new(html)));
+            }
+            Some(s) => s,
         };
-        let is_void = VOID_TAGS.contains(&tag.as_str());
+        if let Some(component) = self.resolve_component(tag, ctx)? {
+            return self.evaluate_component(tag, component, ctx);
+        }
+        let mut html = HTMLElement::new(tag.as_string());
+        for attribute in &self.attributes {
+            html.attributes.insert(attribute.as_string());
+        }
+        for (key, value) in &self.arguments {
+            let value = unwind!(evaluate_value(value, ctx)?);
+            html.set_argument(&key.as_string(), value)?;
+        }
+        if html.is_void && !self.children.is_empty() {
+            let msg = format!("Void element `<{}>` cannot have children", html.tag);
+            return Err(SDLError::invalid_template(msg, tag.range));
+        }
+        if let Some(signal) = evaluate_children(&mut html, &self.children, ctx)? {
+            return Ok(signal);
+        }
+        Ok(ASTKind::HTMLElement(Box::new(html)))
+    }
+}
 
-        // let class = BTreeSet::new();
+impl Template {
+    /// A tag that resolves to a function or a class is a component, a namespaced or capitalized tag must resolve.
+    fn resolve_component(&self, tag: &ASTNode, ctx: &SDLContext) -> Result<Option<ASTNode>> {
+        let symbol = match &tag.kind {
+            ASTKind::Symbol(s) => s,
+            _ => return Ok(None),
+        };
+        match ctx.resolve(symbol) {
+            Some(s) if matches!(s.kind, ASTKind::Function(_) | ASTKind::NativeFunction(_) | ASTKind::Class(_)) => Ok(Some(s)),
+            None if !symbol.namespace().is_empty() || symbol.name().starts_with(char::is_uppercase) => {
+                Err(SDLError::undefined_symbol(format!("{:?}", symbol), tag.range))
+            }
+            _ => Ok(None),
+        }
+    }
+    /// Call the component with attributes as keyword arguments.
+    fn evaluate_component(&self, tag: &ASTNode, component: ASTNode, ctx: &mut SDLContext) -> Result<ASTKind> {
+        let mut keywords = vec![];
+        for attribute in &self.attributes {
+            keywords.push((attribute.to_owned(), ASTNode::boolean(true, attribute.range)));
+        }
+        for (key, value) in &self.arguments {
+            keywords.push((key.to_owned(), unwind!(evaluate_value(value, ctx)?)));
+        }
+        let mut children = HTMLElement::fragment();
+        if let Some(signal) = evaluate_children(&mut children, &self.children, ctx)? {
+            return Ok(signal);
+        }
+        Ok(call_component(&component, keywords, children, ctx, tag.range)?.kind)
+    }
+}
 
-        // if let Some(s) = &self.class {
-        //     match &s.kind {
-        //         ASTKind::String(s) => {
-        //             for i in s.split(" ") {
-        //                 class.insert(i.to_string())
-        //             }
-        //         }
-        //         _ => ()
-        //     }
-        // }
-        unimplemented!()
-        // let html =
-        //     HTMLElement { is_void, tag, id: vec![], class, attributes: Default::default(), arguments: Default::default(), children: vec![] };
-        // Ok(Value::HTMLElement(Box::new(html)))
+/// Evaluate `children` into `html`, a `return` or `break` raised by a child interrupts the template.
+pub(super) fn evaluate_children(html: &mut HTMLElement, children: &[ASTNode], ctx: &mut SDLContext) -> Result<Option<ASTKind>> {
+    for child in children {
+        let child = child.evaluate(ctx)?;
+        if let ASTKind::Signal(_) = child.kind {
+            return Ok(Some(child.kind));
+        }
+        html.add_child(child);
+    }
+    Ok(None)
+}
+
+/// Children are passed as a fragment named `children` when there are any or when the component declares it.
+///
+/// A class component is instantiated with the arguments as fields and expanded by its `render` method.
+pub(super) fn call_component(
+    component: &ASTNode,
+    mut keywords: Vec<(ASTNode, ASTNode)>,
+    children: HTMLElement,
+    ctx: &SDLContext,
+    position: Range,
+) -> Result<ASTNode> {
+    let declared = match &component.kind {
+        ASTKind::Function(f) => f.define.parameter_names().iter().any(|name| name == "children"),
+        ASTKind::Class(c) => c.fields.contains_key("children"),
+        _ => false,
+    };
+    if declared || !children.children.is_empty() {
+        let value = ASTNode { kind: ASTKind::HTMLElement(Box::new(children)), range: position };
+        keywords.push((ASTNode::string(String::from("children"), position), value));
+    }
+    let out = component.call(CallApply { arguments: vec![], keywords }, position)?;
+    let class = match &component.kind {
+        ASTKind::Class(c) => c,
+        _ => return Ok(out),
+    };
+    let render = class.methods.get("render").cloned().or_else(|| ctx.get_extension(&class.name, "render"));
+    match render {
+        Some(f) => f.call(CallApply { arguments: vec![out], keywords: vec![] }, position),
+        None => Err(SDLError::invalid_template(format!("Class `{}` has no `render` method to be used as a component", class.name), position)),
+    }
+}
+
+impl HTMLElement {
+    /// `class` and `id` are collected separately, `true` becomes a boolean attribute while `false` and `null` are dropped.
+    fn set_argument(&mut self, key: &str, value: ASTNode) -> Result<()> {
+        match (key, &value.kind) {
+            (_, ASTKind::Null) | (_, ASTKind::Boolean(false)) => (),
+            ("class", _) => self.set_class(&value)?,
+            ("id", ASTKind::String(s)) => self.id = Some(s.to_owned()),
+            ("id", ASTKind::Integer(n)) => self.id = Some(n.to_string()),
+            ("id", _) => {
+                let msg = format!("Attribute `id` expected `String`, found `{}`", get_variant_name(&value.kind));
+                return Err(SDLError::invalid_type(msg, value.range));
+            }
+            (_, ASTKind::Boolean(true)) => {
+                self.attributes.insert(key.to_string());
+            }
+            _ => {
+                self.arguments.insert(key.to_string(), value);
+            }
+        }
+        Ok(())
+    }
+    /// Accepts `"a b"`, `["a", "b"]` or `{a: true, b: false}`.
+    fn set_class(&mut self, value: &ASTNode) -> Result<()> {
+        match &value.kind {
+            ASTKind::Null | ASTKind::Boolean(false) => (),
+            ASTKind::String(s) => self.add_class(s),
+            ASTKind::List(v) => {
+                for e in v {
+                    self.set_class(e)?
+                }
+            }
+            ASTKind::Dict(v) => {
+                for (k, e) in v.iter() {
+                    if e.kind.is_truthy() {
+                        self.add_class(k)
+                    }
+                }
+            }
+            _ => {
+                let msg = format!("Attribute `class` expected `String`, `List` or `Dict`, found `{}`", get_variant_name(&value.kind));
+                return Err(SDLError::invalid_type(msg, value.range));
+            }
+        }
+        Ok(())
     }
 }
 
 impl Evaluate for Symbol {
     fn evaluate(&self, ctx: &mut SDLContext) -> Result<ASTNode> {
-        unimplemented!()
-        // Ok(ctx.get(&self.name()))
+        match ctx.resolve(self) {
+            Some(s) => Ok(ASTNode { kind: s.kind, range: self.range() }),
+            None => Err(SDLError::undefined_symbol(format!("{:?}", self), self.range())),
+        }
     }
 }
 
@@ -48,12 +177,15 @@ impl Evaluate for StringExpression {
     }
 
     fn evaluate_kind(&self, ctx: &mut SDLContext) -> Result<ASTKind> {
-        let out = match self.handler {
-            Some(_) => unimplemented!(),
+        let out = match &self.handler {
+            Some(handler) => return Ok(self.evaluate_handler(handler, ctx)?.kind),
             None => {
                 let mut out = String::new();
                 for e in &self.inner {
-                    e.evaluate(ctx)?.render(&mut out, ctx)?;
+                    match unwind!(evaluate_value(e, ctx)?).unwrap_block().kind {
+                        ASTKind::String(s) => out.push_str(&s),
+                        value => value.render(&mut out, ctx)?,
+                    }
                 }
                 ASTKind::String(out)
             }
@@ -61,3 +193,31 @@ impl Evaluate for StringExpression {
         Ok(out)
     }
 }
+
+impl StringExpression {
+    /// Functions in scope go first, they are called as `handler(pieces, values)`.
+    fn evaluate_handler(&self, handler: &ASTNode, ctx: &mut SDLContext) -> Result<ASTNode> {
+        let mut pieces = vec![];
+        let mut values = vec![];
+        for (i, e) in self.inner.iter().enumerate() {
+            match i % 2 {
+                0 => pieces.push(e.as_string()),
+                _ => values.push(unwind!(evaluate_value(e, ctx)?).unwrap_block()),
+            }
+        }
+        let range = handler.range;
+        if let ASTKind::Symbol(symbol) = &handler.kind {
+            if let Some(f) = ctx.resolve(symbol).filter(|f| matches!(f.kind, ASTKind::Function(_) | ASTKind::NativeFunction(_))) {
+                let pieces = pieces.into_iter().map(|s| ASTNode::string(s, range)).collect();
+                let arguments = vec![ASTNode::list(pieces, range), ASTNode::list(values, range)];
+                return f.call(CallApply { arguments, ..Default::default() }, range);
+            }
+        }
+        let name = handler.as_string();
+        let f = match ctx.get_string_handler(&name) {
+            Some(s) => s,
+            None => return Err(SDLError::undefined_symbol(name, range)),
+        };
+        f.call(TaggedString { handler: name, pieces, values, position: range }, ctx)
+    }
+}
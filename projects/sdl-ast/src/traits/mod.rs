@@ -3,5 +3,5 @@ mod evaluate;
 #[allow(unused_variables)]
 mod render;
 
-pub use evaluate::{Evaluate, Concat};
+pub use evaluate::Evaluate;
 pub use render::Render;
@@ -0,0 +1,82 @@
+use super::*;
+use crate::{
+    ast::HTMLElement,
+    utils::{escape_attribute, escape_quotes, escape_text, is_script_url, UNSAFE_URL, URL_ATTRIBUTES},
+};
+
+impl Render for HTMLElement {
+    fn render(&self, text: &mut impl Write, ctx: &SDLContext) -> Result<()> {
+        if self.is_fragment() {
+            for e in &self.children {
+                render_child(e, text, ctx)?;
+            }
+            return Ok(());
+        }
+        write!(text, "<{}", self.tag)?;
+        if let Some(id) = &self.id {
+            write!(text, " id=\"{}\"", escape_attribute(id))?;
+        }
+        if !self.class.is_empty() {
+            let class: Vec<_> = self.class.iter().map(|s| s.as_str()).collect();
+            write!(text, " class=\"{}\"", escape_attribute(&class.join(" ")))?;
+        }
+        for (k, v) in &self.arguments {
+            write!(text, " {}=\"", k)?;
+            render_attribute(k, v, text, ctx)?;
+            write!(text, "\"")?;
+        }
+        for k in &self.attributes {
+            write!(text, " {}", k)?;
+        }
+        write!(text, ">")?;
+        if self.is_void {
+            return Ok(());
+        }
+        for e in &self.children {
+            render_child(e, text, ctx)?;
+        }
+        write!(text, "</{}>", self.tag)?;
+        Ok(())
+    }
+}
+
+/// Strings are escaped as text, `HTMLText` is already escaped and written as is.
+fn render_child(node: &ASTNode, text: &mut impl Write, ctx: &SDLContext) -> Result<()> {
+    match &node.kind {
+        ASTKind::HTMLText(s) => write!(text, "{}", s)?,
+        ASTKind::String(s) => write!(text, "{}", escape_text(s))?,
+        ASTKind::HTMLElement(_) | ASTKind::Integer(_) | ASTKind::Decimal(_) | ASTKind::Boolean(_) => node.render(text, ctx)?,
+        ASTKind::List(v) => {
+            for e in v {
+                render_child(e, text, ctx)?
+            }
+        }
+        _ => {
+            let mut out = String::new();
+            node.render(&mut out, ctx)?;
+            write!(text, "{}", escape_text(&out))?
+        }
+    }
+    Ok(())
+}
+
+/// Values are escaped for a double quoted attribute, script urls in [`URL_ATTRIBUTES`] are replaced.
+///
+/// `HTMLText` is already escaped, only its quotes are.
+fn render_attribute(key: &str, node: &ASTNode, text: &mut impl Write, ctx: &SDLContext) -> Result<()> {
+    let value = match &node.kind {
+        ASTKind::HTMLText(s) => s.to_owned(),
+        ASTKind::String(s) => s.to_owned(),
+        _ => {
+            let mut out = String::new();
+            node.render(&mut out, ctx)?;
+            out
+        }
+    };
+    match URL_ATTRIBUTES.contains(&key) && is_script_url(&value) {
+        true => write!(text, "{}", UNSAFE_URL)?,
+        false if matches!(node.kind, ASTKind::HTMLText(_)) => write!(text, "{}", escape_quotes(&value))?,
+        false => write!(text, "{}", escape_attribute(&value))?,
+    }
+    Ok(())
+}
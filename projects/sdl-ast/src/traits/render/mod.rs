@@ -1,4 +1,6 @@
-use crate::{Result, SDLContext, ASTNode, ASTKind};
+mod html;
+
+use crate::{utils::{ensure_stack, escape_string}, Result, SDLContext, ASTNode, ASTKind};
 use std::fmt::Write;
 
 pub trait Render {
@@ -10,7 +12,7 @@ pub trait Render {
 
 impl Render for ASTNode {
     fn render(&self, text: &mut impl Write, ctx: &SDLContext) -> Result<()> {
-        self.kind.render(text, ctx)
+        ensure_stack(|| self.kind.render(text, ctx))
     }
 }
 
@@ -27,7 +29,8 @@ impl Render for ASTKind {
             Self::Boolean(v) => write!(text, "{}", v)?,
             Self::Integer(v) => write!(text, "{}", v)?,
             Self::Decimal(v) => write!(text, "{}", v)?,
-            Self::HTMLText(v) | Self::String(v) => write!(text, "{:?}", v)?,
+            Self::HTMLText(v) => write!(text, "{}", v)?,
+            Self::String(v) => write!(text, "{}", escape_string(v))?,
             Self::List(v) => {
                 write!(text, "[")?;
                 for (i, e) in v.iter().enumerate() {
@@ -38,9 +41,34 @@ impl Render for ASTKind {
                 }
                 write!(text, "]")?;
             }
+            Self::Dict(v) => {
+                write!(text, "{{")?;
+                for (i, (k, e)) in v.iter().enumerate() {
+                    write!(text, "{}: ", escape_string(k))?;
+                    e.render(text, ctx)?;
+                    if i != v.len() - 1 {
+                        write!(text, ", ")?;
+                    }
+                }
+                write!(text, "}}")?;
+            }
+            Self::Function(v) => write!(text, "{:?}", v)?,
+            Self::NativeFunction(v) => write!(text, "{:?}", v)?,
+            Self::Generator(v) => write!(text, "{:?}", v)?,
+            Self::Class(v) => write!(text, "{:?}", v)?,
+            Self::Object(v) => {
+                write!(text, "{} {{", v.class.name)?;
+                for (i, (k, e)) in v.fields.iter().enumerate() {
+                    write!(text, "{}: ", k)?;
+                    e.render(text, ctx)?;
+                    if i != v.fields.len() - 1 {
+                        write!(text, ", ")?;
+                    }
+                }
+                write!(text, "}}")?;
+            }
+            Self::HTMLElement(html) => html.render(text, ctx)?,
             _ => unimplemented!("{:?}", self)
-            // Self::Dict(v) => write!(text, "{:#?}", v)?,
-            // Self::HTMLElement(html) => html.render(text, ctx)?,
         };
         Ok(())
     }
@@ -48,14 +76,3 @@ impl Render for ASTKind {
         unimplemented!()
     }
 }
-
-// impl Render for HTMLElement {
-//     fn render(&self, text: &mut impl Write, ctx: &SDLContext) -> Result<()> {
-//         let out = match self.is_void {
-//             true => format!("<{tag}>", tag = self.tag),
-//             false => format!("<{tag}></{tag}>", tag = self.tag),
-//         };
-//         write!(text, "{}", out)?;
-//         Ok(())
-//     }
-// }
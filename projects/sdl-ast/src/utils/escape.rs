@@ -0,0 +1,71 @@
+/// Escape text placed between tags.
+pub fn escape_text(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Escape text placed inside a quoted attribute value.
+pub fn escape_attribute(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Escape quotes of html, so it can be placed inside a quoted attribute value.
+pub(crate) fn escape_quotes(input: &str) -> String {
+    input.replace('"', "&quot;").replace('\'', "&#39;")
+}
+
+/// Quote a string as a SDL string literal.
+pub fn escape_string(input: &str) -> String {
+    let mut out = String::with_capacity(input.len() + 2);
+    out.push('"');
+    for c in input.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            _ if c.is_control() => out.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Replacement of an url that would run a script.
+pub(crate) static UNSAFE_URL: &str = "about:invalid";
+
+/// Attributes whose value is loaded as an url.
+pub(crate) static URL_ATTRIBUTES: &[&str] = &["href", "src", "action", "formaction"];
+
+/// Browsers ignore leading spaces and control characters, and drop tabs and newlines anywhere in the scheme.
+pub fn is_script_url(url: &str) -> bool {
+    let scheme: String = url
+        .trim_start_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .take_while(|c| *c != ':')
+        .collect();
+    let scheme = scheme.to_ascii_lowercase();
+    url.contains(':') && (scheme == "javascript" || scheme == "vbscript")
+}
@@ -1,7 +1,20 @@
+mod escape;
 mod transform;
 
+pub use escape::{escape_attribute, escape_string, escape_text, is_script_url};
+pub(crate) use escape::{escape_quotes, UNSAFE_URL, URL_ATTRIBUTES};
+
 use std::fmt::Debug;
 
+/// Stack needed by one step of a recursive walk, `f` runs on a new segment when less is left.
+const RED_ZONE: usize = 128 * 1024;
+const STACK_PER_RECURSION: usize = 1024 * 1024;
+
+/// Run a step of a recursive walk over the AST, deep recursion grows the stack instead of overflowing it.
+pub(crate) fn ensure_stack<R>(f: impl FnOnce() -> R) -> R {
+    stacker::maybe_grow(RED_ZONE, STACK_PER_RECURSION, f)
+}
+
 pub fn get_variant_name(e: impl Debug) -> String {
     let mut out = String::new();
     for c in format!("{:?}", e).chars() {
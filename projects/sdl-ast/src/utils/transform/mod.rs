@@ -3,12 +3,26 @@ use crate::{ast::Symbol, ASTKind, ASTNode};
 impl ASTNode {
     pub fn as_string(&self) -> String {
         match &self.kind {
-            ASTKind::HTMLText(s) => format!("{}", s),
+            ASTKind::HTMLText(s) | ASTKind::String(s) => s.to_owned(),
             ASTKind::Symbol(s) => format!("{:?}", s),
             ASTKind::Operator(s) => format!("{:?}", s),
             _ => String::new(),
         }
     }
+    /// Take the value out of an evaluated block, an empty block is `null`.
+    pub fn unwrap_block(self) -> ASTNode {
+        match self.kind {
+            ASTKind::Block(mut v) if v.len() == 1 => v.remove(0).unwrap_block(),
+            ASTKind::Block(v) if v.is_empty() => ASTNode { kind: ASTKind::Null, range: self.range },
+            _ => self,
+        }
+    }
+    pub fn as_symbol_path(self) -> Vec<ASTNode> {
+        match self.kind {
+            ASTKind::Symbol(s) => s.path,
+            _ => vec![self],
+        }
+    }
     pub fn as_symbol(self) -> ASTNode {
         match &self.kind {
             ASTKind::HTMLText(_) => {
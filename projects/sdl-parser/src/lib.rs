@@ -1,6 +1,6 @@
-#![feature(once_cell)]
-
+mod loader;
 mod parser;
 
-pub use sdl_ast::{SDLError, Result};
+pub use sdl_ast::{SDLError, SDLErrorKind, SyntaxErrorCode, Result};
+pub use loader::{FileLoader, MemoryLoader};
 pub use parser::ParserConfig;
@@ -0,0 +1,53 @@
+use crate::{ParserConfig, Result, SDLError};
+use sdl_ast::{ast::ModulePath, ASTNode, ModuleLoader};
+use std::{collections::HashMap, path::PathBuf};
+
+/// Load modules from `.sdl` files under a root directory, eg: `a::b` is `root/a/b.sdl`.
+#[derive(Clone, Debug)]
+pub struct FileLoader {
+    pub root: PathBuf,
+}
+
+/// Load modules from sources registered by id, eg: `a::b` is `"a/b.sdl"`.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryLoader {
+    pub files: HashMap<String, String>,
+}
+
+impl FileLoader {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl MemoryLoader {
+    pub fn insert(&mut self, id: impl Into<String>, source: impl Into<String>) {
+        self.files.insert(id.into(), source.into());
+    }
+}
+
+impl ModuleLoader for FileLoader {
+    fn resolve(&self, path: &ModulePath, base: Option<&str>) -> Option<String> {
+        let id = path.to_id(base);
+        self.root.join(&id).is_file().then_some(id)
+    }
+    fn load(&self, id: &str) -> Result<ASTNode> {
+        match std::fs::read_to_string(self.root.join(id)) {
+            Ok(o) => ParserConfig::default().parse(o),
+            Err(_) => Err(SDLError::file_not_found(id, Default::default())),
+        }
+    }
+}
+
+impl ModuleLoader for MemoryLoader {
+    fn resolve(&self, path: &ModulePath, base: Option<&str>) -> Option<String> {
+        let id = path.to_id(base);
+        self.files.contains_key(&id).then_some(id)
+    }
+    fn load(&self, id: &str) -> Result<ASTNode> {
+        match self.files.get(id) {
+            Some(s) => ParserConfig::default().parse(s),
+            None => Err(SDLError::file_not_found(id, Default::default())),
+        }
+    }
+}
@@ -1,17 +1,62 @@
-use sdl_ast::ast::{Range, Position};
+use crate::{Result, SDLError};
+use sdl_ast::{
+    ast::{Position, Range},
+    SyntaxErrorCode,
+};
 use sdl_pest::{Pair, Rule};
+use std::{cell::Cell, num::NonZeroUsize, sync::Once};
 
 pub struct ParserConfig {
-    pub tab_size: usize,
+    /// How deep expressions, blocks and templates can be nested in each other, deeper input is rejected with
+    /// [`SyntaxErrorCode::NestingTooDeep`] instead of overflowing the stack.
+    pub max_depth: usize,
+    /// Nesting of the node being built.
+    depth: Cell<usize>,
 }
 
+/// Steps the grammar can take on one input unless [`ParserConfig::set_call_limit`] was called.
+const DEFAULT_CALL_LIMIT: usize = 100_000_000;
+
+/// Guards the default call limit, so it is set before the first parse and never over a custom limit.
+static CALL_LIMIT: Once = Once::new();
+
 impl Default for ParserConfig {
     fn default() -> Self {
-        Self { tab_size: 4 }
+        Self { max_depth: 128, depth: Cell::new(0) }
+    }
+}
+
+/// One more level of nesting, released on drop.
+pub(super) struct Depth<'a>(&'a Cell<usize>);
+
+impl Drop for Depth<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1)
     }
 }
 
 impl ParserConfig {
+    /// How many steps the grammar can take on one input, more is rejected with [`SyntaxErrorCode::CallLimit`].
+    ///
+    /// The limit is kept by pest for the whole process, it applies to every parser on every thread from the next
+    /// input on. The default is 100 000 000 steps, `None` removes the limit.
+    pub fn set_call_limit(limit: Option<usize>) {
+        CALL_LIMIT.call_once(|| {});
+        sdl_pest::pest::set_call_limit(limit.and_then(NonZeroUsize::new))
+    }
+    /// Set the default call limit once, before the first input is parsed.
+    pub(super) fn default_call_limit() {
+        CALL_LIMIT.call_once(|| sdl_pest::pest::set_call_limit(NonZeroUsize::new(DEFAULT_CALL_LIMIT)))
+    }
+    /// Enter a nested node, fails if it is deeper than [`ParserConfig::max_depth`].
+    pub(super) fn nest(&self, pair: &Pair<Rule>) -> Result<Depth<'_>> {
+        if self.depth.get() >= self.max_depth {
+            let msg = format!("Nesting is deeper than the limit of {}", self.max_depth);
+            return Err(SDLError::syntax_error(SyntaxErrorCode::NestingTooDeep, msg, vec![], self.get_position(pair)));
+        }
+        self.depth.set(self.depth.get() + 1);
+        Ok(Depth(&self.depth))
+    }
     pub fn get_position(&self, s: &Pair<Rule>) -> Range {
         let us = s.as_span().start_pos().line_col();
         let es = s.as_span().end_pos().line_col();
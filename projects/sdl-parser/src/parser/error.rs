@@ -0,0 +1,73 @@
+use super::*;
+use sdl_ast::SyntaxErrorCode;
+use sdl_pest::{
+    pest::error::{ErrorVariant, InputLocation, LineColLocation},
+    Error,
+};
+
+impl ParserConfig {
+    /// Input rejected by the grammar, `expected` lists the rules that could have matched.
+    pub(super) fn syntax_error(&self, e: Error<Rule>, input: &str) -> SDLError {
+        let offset = match e.location {
+            InputLocation::Pos(p) | InputLocation::Span((p, _)) => p,
+        };
+        let found = match input.get(offset..).and_then(|s| s.chars().next()) {
+            Some(c) => format!("`{}`", c.escape_debug()),
+            None => "end of input".to_string(),
+        };
+        let range = match e.line_col {
+            LineColLocation::Pos(p) => Range { start: position(p), end: position(p) },
+            LineColLocation::Span(s, e) => Range { start: position(s), end: position(e) },
+        };
+        let (expected, msg) = match e.variant {
+            ErrorVariant::ParsingError { positives, negatives } => {
+                let expected: Vec<String> = positives.iter().map(|r| format!("{:?}", r)).collect();
+                let msg = match (expected.is_empty(), negatives.is_empty()) {
+                    (false, _) => format!("Expected {}, found {}", join_rules(&expected), found),
+                    (true, false) => {
+                        let negatives: Vec<String> = negatives.iter().map(|r| format!("{:?}", r)).collect();
+                        format!("Unexpected {}, found {}", join_rules(&negatives), found)
+                    }
+                    (true, true) => format!("Unexpected {}", found),
+                };
+                (expected, msg)
+            }
+            ErrorVariant::CustomError { message } => (vec![], message),
+        };
+        // limits of pest are reported as custom errors
+        let (code, msg) = match msg.as_str() {
+            "stack limit reached" => (SyntaxErrorCode::NestingTooDeep, "Nesting is too deep to be parsed".to_string()),
+            "call limit reached" => (SyntaxErrorCode::CallLimit, "Input is too complex to be parsed".to_string()),
+            _ => (SyntaxErrorCode::UnexpectedToken, msg),
+        };
+        SDLError::syntax_error(code, msg, expected, range)
+    }
+    /// A rule accepted by the grammar that the parser does not know how to build.
+    pub(super) fn unexpected(&self, pair: &Pair<Rule>) -> SDLError {
+        let msg = format!("Unexpected `{:?}` rule `{}`", pair.as_rule(), pair.as_str());
+        SDLError::syntax_error(SyntaxErrorCode::UnexpectedRule, msg, vec![], self.get_position(pair))
+    }
+    /// A rule that is required by the grammar is missing.
+    pub(super) fn missing(&self, range: Range, rule: &str) -> SDLError {
+        let msg = format!("Missing `{}` rule", rule);
+        SDLError::syntax_error(SyntaxErrorCode::UnexpectedRule, msg, vec![rule.to_string()], range)
+    }
+    pub(super) fn first_inner<'i>(&self, pairs: Pair<'i, Rule>) -> Result<Pair<'i, Rule>> {
+        let range = self.get_position(&pairs);
+        let rule = format!("{:?}", pairs.as_rule());
+        pairs.into_inner().next().ok_or_else(|| self.missing(range, &rule))
+    }
+}
+
+fn position((line, column): (usize, usize)) -> Position {
+    Position { line: line.saturating_sub(1) as u64, character: column.saturating_sub(1) as u64 }
+}
+
+/// `a`, `a` or `b`, `a`, `b` or `c`
+fn join_rules(rules: &[String]) -> String {
+    let quoted: Vec<String> = rules.iter().map(|r| format!("`{}`", r)).collect();
+    match quoted.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        _ => quoted.concat(),
+    }
+}
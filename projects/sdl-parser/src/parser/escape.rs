@@ -0,0 +1,74 @@
+//! Escape sequences of string literals, the same table is used by every kind of quote.
+//!
+//! | Escape                    | Value                                                                   |
+//! | ------------------------- | ----------------------------------------------------------------------- |
+//! | `\n`, `\r`, `\t`          | line feed, carriage return, tab                                         |
+//! | `\0`                      | null                                                                    |
+//! | `\\`                      | backslash                                                               |
+//! | `\"`, `\'`, `` \` ``, `\´` | the quote itself                                                        |
+//! | `\{`, `\}`                | the brace itself, `\{{ x }}` is not an interpolation                    |
+//! | `\x41`                    | ascii character, exactly 2 hex digits up to `7F`                        |
+//! | `\u{1F600}`               | unicode scalar value, 1 to 6 hex digits                                 |
+//! | `\` at the end of a line  | line continuation, the line break and the indent that follows are skipped |
+//!
+//! Any other escape is an error.
+use super::*;
+
+/// Value of a `StringEscape` token, or the reason why it is invalid.
+pub(super) fn unescape(text: &str) -> std::result::Result<String, String> {
+    let body = &text[1..];
+    let c = match body.chars().next() {
+        Some(c) => c,
+        None => return Err("Escape `\\` is not followed by a character".to_string()),
+    };
+    let out = match c {
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        '0' => '\0',
+        '\\' | '"' | '\'' | '`' | '´' | '{' | '}' => c,
+        '\r' | '\n' => return Ok(String::new()),
+        'x' => {
+            let hex = &body[1..];
+            let n = match hex.len() == 2 {
+                true => u32::from_str_radix(hex, 16).ok(),
+                false => None,
+            };
+            match n {
+                Some(n) if n <= 0x7F => char::from(n as u8),
+                Some(n) => return Err(format!("Escape `{}` must be at most `\\x7F`, use `\\u{{{:X}}}` instead", text, n)),
+                None => return Err(format!("Escape `{}` expected 2 hex digits", text)),
+            }
+        }
+        'u' if body.starts_with("u{") => {
+            let hex = match body[2..].strip_suffix('}') {
+                Some(s) => s,
+                None => return Err(format!("Escape `{}` is missing the closing `}}`", text)),
+            };
+            let n = match hex.len() {
+                1..=6 => u32::from_str_radix(hex, 16).ok(),
+                _ => None,
+            };
+            match n.map(char::from_u32) {
+                Some(Some(c)) => c,
+                Some(None) => return Err(format!("Escape `{}` is not a unicode scalar value", text)),
+                None => return Err(format!("Escape `{}` expected 1 to 6 hex digits", text)),
+            }
+        }
+        _ => return Err(format!("Unknown escape sequence `{}`", text)),
+    };
+    Ok(out.to_string())
+}
+
+/// Report the first invalid escape before the tree is built.
+pub(super) fn check_escapes(config: &ParserConfig, pairs: &Pairs<Rule>) -> Result<()> {
+    for pair in pairs.clone().flatten() {
+        if pair.as_rule() != Rule::StringEscape {
+            continue;
+        }
+        if let Err(msg) = unescape(pair.as_str()) {
+            return Err(SDLError::invalid_escape(msg, config.get_position(&pair)));
+        }
+    }
+    Ok(())
+}
@@ -1,95 +1,101 @@
 mod config;
+mod error;
+mod escape;
 mod regroup;
+mod text;
 
 pub use crate::parser::config::ParserConfig;
-use crate::{parser::regroup::PREC_CLIMBER, Result, SDLError};
-use sdl_ast::{ast::CallChain, Template, ASTNode, ASTKind};
+use crate::{
+    parser::{
+        escape::{check_escapes, unescape},
+        regroup::{Operand, PRATT_PARSER},
+        text::TextBuffer,
+    },
+    Result, SDLError,
+};
+use sdl_ast::{
+    ast::{Annotation, AssignKind, CallApply, CallChain, ControlKind, ImportItem, IndexMap, ModulePath, Position, Range, SliceIndex, TemplateKind},
+    ASTKind, ASTNode, Template,
+};
 use sdl_pest::{Pair, Pairs, Parser, Rule, SDLParser};
 
-macro_rules! debug_cases {
-    ($i:ident) => {{
-        println!("Rule::{:?}=>continue,", $i.as_rule());
-        println!("Span: {:?}", $i.as_span());
-        println!("Text: {}", $i.as_str());
-        unreachable!();
-    }};
-}
-
 impl ParserConfig {
     pub fn parse(&mut self, input: impl AsRef<str>) -> Result<ASTNode> {
-        let input = input.as_ref().replace("\r\n", "\n").replace("\\\n", "").replace("\t", &" ".repeat(self.tab_size));
-        match SDLParser::parse(Rule::program, &input) {
-            Ok(o) => {Ok(self.parse_program(o))}
-            Err(e) => {
-                Err(
-                    SDLError::lexer_error(e.to_string())
-                )
-            }
-        }
-
-
+        // ranges stay the same, `\r` is always the last character of a line
+        let input = input.as_ref().replace("\r\n", "\n");
+        Self::default_call_limit();
+        let pairs = SDLParser::parse(Rule::program, &input).map_err(|e| self.syntax_error(e, &input))?;
+        check_escapes(self, &pairs)?;
+        self.parse_program(pairs)
     }
-    fn parse_program(&self, pairs: Pairs<Rule>) -> ASTNode {
+    fn parse_program(&self, pairs: Pairs<Rule>) -> Result<ASTNode> {
         let mut codes = vec![];
         for pair in pairs {
             if let Rule::statement = pair.as_rule() {
-                codes.push(self.parse_statement(pair));
+                codes.push(self.parse_statement(pair)?);
             };
         }
-        ASTNode::program(codes)
+        Ok(ASTNode::program(codes))
     }
-    fn parse_statement(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_statement(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
         let range = self.get_position(&pairs);
         let mut codes = vec![];
         for pair in pairs.into_inner() {
             let code = match pair.as_rule() {
-                Rule::WHITESPACE => continue,
-                Rule::expression => self.parse_expression(pair),
-                Rule::if_statement => self.parse_if_else(pair),
-                Rule::for_statement => self.parse_for_in(pair),
-                Rule::assign_statement => self.parse_assign(pair),
-                _ => debug_cases!(pair),
+                Rule::WHITESPACE | Rule::eos | Rule::emptyStatement => continue,
+                Rule::expression => self.parse_expression(pair)?,
+                Rule::if_statement => self.parse_if_else(pair)?,
+                Rule::for_statement => self.parse_for_in(pair)?,
+                Rule::assign_statement => self.parse_assign(pair)?,
+                Rule::define_statement => self.parse_define(pair)?,
+                Rule::extendStatement => self.parse_extend(pair)?,
+                Rule::importStatement => self.parse_import(pair)?,
+                Rule::re_control => self.parse_control(pair)?,
+                Rule::classStatement => self.parse_class(pair)?,
+                Rule::annotation => self.parse_annotation(pair)?,
+                _ => return Err(self.unexpected(&pair)),
             };
             codes.push(code);
         }
-        ASTNode {
+        Ok(ASTNode {
             kind: ASTKind::Statement(codes),
             range
-        }
+        })
     }
-    fn parse_block(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_block(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let _depth = self.nest(&pairs)?;
         let range = self.get_position(&pairs);
         let mut codes = vec![];
         for pair in pairs.into_inner() {
             let code = match pair.as_rule() {
-                Rule::statement =>self.parse_statement(pair),
-                _ => unreachable!(),
+                Rule::statement =>self.parse_statement(pair)?,
+                _ => return Err(self.unexpected(&pair)),
             };
             codes.push(code);
         }
-        ASTNode {
+        Ok(ASTNode {
             kind: ASTKind::Block(codes),
             range
-        }
+        })
     }
 }
 
 impl ParserConfig {
-    fn parse_if_else(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_if_else(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
         let r = self.get_position(&pairs);
         let mut conditions = vec![];
         let mut actions = vec![];
         for pair in pairs.into_inner() {
             match pair.as_rule() {
-                Rule::expr => conditions.push(self.parse_expr(pair)),
-                Rule::block => actions.push(self.parse_block(pair)),
-                _ => debug_cases!(pair),
+                Rule::expr => conditions.push(self.parse_expr(pair)?),
+                Rule::block => actions.push(self.parse_block(pair)?),
+                _ => return Err(self.unexpected(&pair)),
             };
         }
-        ASTNode::if_else_chain(conditions, actions, r)
+        Ok(ASTNode::if_else_chain(conditions, actions, r))
     }
 
-    fn parse_for_in(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_for_in(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
         let r = self.get_position(&pairs);
         let mut guard = None;
         let mut for_else = None;
@@ -97,74 +103,111 @@ impl ParserConfig {
         for pair in pairs.into_inner() {
             match pair.as_rule() {
                 Rule::WHITESPACE => continue,
-                Rule::pattern | Rule::pattern_bare => pattern = self.parse_pattern(pair),
-                Rule::expr => terms = self.parse_expr(pair),
-                Rule::block => block = self.parse_block(pair),
-                Rule::for_if => guard = Some(self.parse_expr(pair.into_inner().nth(0).unwrap())),
-                Rule::for_else => for_else = Some(self.parse_block(pair.into_inner().nth(0).unwrap())),
-                // _ => debug_cases!(pair),
-                _ => unreachable!(),
+                Rule::pattern | Rule::pattern_bare => pattern = self.parse_pattern(pair)?,
+                Rule::expr => terms = self.parse_expr(pair)?,
+                Rule::block => block = self.parse_block(pair)?,
+                Rule::for_if => guard = Some(self.parse_expr(self.first_inner(pair)?)?),
+                Rule::for_else => for_else = Some(self.parse_block(self.first_inner(pair)?)?),
+                _ => return Err(self.unexpected(&pair)),
             };
         }
 
-        ASTNode::for_in_loop(pattern, terms, block, guard, for_else, r)
+        Ok(ASTNode::for_in_loop(pattern, terms, block, guard, for_else, r))
     }
 }
 
 impl ParserConfig {
-    fn parse_expression(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_expression(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
         let r = self.get_position(&pairs);
         let mut terms = pairs.into_inner();
-        let expr = self.parse_expr(terms.next().unwrap());
+        let expr = match terms.next() {
+            Some(s) => self.parse_expr(s)?,
+            None => return Err(self.missing(r, "expr")),
+        };
         let eos = terms.next().is_some();
-        ASTNode::expression(expr, eos, r)
+        Ok(ASTNode::expression(expr, eos, r))
     }
 
     #[rustfmt::skip]
-    fn parse_expr(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_expr(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let _depth = self.nest(&pairs)?;
         let r = self.get_position(&pairs);
-        PREC_CLIMBER.climb(
-            pairs.into_inner(),
-            |pair: Pair<Rule>| match pair.as_rule() {
-                //Rule::expr => self.parse_expr(pair),
-                Rule::term => self.parse_term(pair),
-                _ => debug_cases!(pair),
-            },
-            |left: ASTNode, op: Pair<Rule>, right: ASTNode| match op.as_rule() {
-                _ => ASTNode::infix_expression(self.parse_operation(op, "="), left, right, r),
-            },
-        )
+        let out = PRATT_PARSER
+            .map_primary(|pair: Pair<Rule>| match pair.as_rule() {
+                Rule::term => self.parse_operand(pair),
+                _ => Err(self.unexpected(&pair)),
+            })
+            .map_infix(move |left: Result<Operand>, op: Pair<Rule>, right: Result<Operand>| {
+                let (left, right) = (left?, right?.finish());
+                match op.as_rule() {
+                    Rule::Set => Ok(Operand::new(ASTNode::assign(AssignKind::Set, left.finish(), right, r))),
+                    // `^` binds tighter than prefix operators, `-2 ^ 2` is `-(2 ^ 2)`
+                    Rule::Power => {
+                        let base = ASTNode::infix_expression(self.parse_operation(op, "="), left.base, right, r);
+                        Ok(Operand { prefix: left.prefix, base, range: r })
+                    }
+                    _ => Ok(Operand::new(ASTNode::infix_expression(self.parse_operation(op, "="), left.finish(), right, r))),
+                }
+            })
+            .parse(pairs.into_inner())?;
+        Ok(out.finish())
     }
 
-    fn parse_term(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_term(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        Ok(self.parse_operand(pairs)?.finish())
+    }
+    /// A term with its prefix operators not applied yet.
+    fn parse_operand(&self, pairs: Pair<Rule>) -> Result<Operand> {
         let r = self.get_position(&pairs);
         let mut base = CallChain::default();
-        // let mut prefix = vec![];
-        // let mut suffix = vec![];
+        let mut prefix = vec![];
         for pair in pairs.into_inner() {
             match pair.as_rule() {
                 Rule::WHITESPACE|Rule::COMMENT => continue,
-                Rule::data => base = CallChain::new(self.parse_data(pair)) ,
-                Rule::dot_call=> base += self.parse_dot_call(pair),
-                //Rule::term => base = self.parse_node(pair),
-                //Rule::Prefix => prefix.push(pair.as_str().to_string()),
-                //Rule::Suffix => suffix.push(pair.as_str().to_string()),
-                _ => debug_cases!(pair),
-                //_ => unreachable!(),
+                Rule::data => base = CallChain::new(self.parse_data(pair)?) ,
+                Rule::expr => base = CallChain::new(self.parse_expr(pair)?),
+                Rule::dot_call=> base += self.parse_dot_call(pair)?,
+                Rule::apply => base += self.parse_apply(pair)?,
+                Rule::slice => base += self.parse_slice(pair)?,
+                Rule::Prefix => prefix.push(self.parse_operation(pair, "<")),
+                // suffix binds everything on its left, `a.b?.c` is `(a.b)?.c`
+                Rule::Suffix => {
+                    let op = self.parse_operation(pair, ">");
+                    let range = Range { start: r.start, end: op.range.end };
+                    let lhs = self.finish_chain(base, range);
+                    base = CallChain::new(ASTNode::suffix_expression(op, lhs, range))
+                }
+                _ => return Err(self.unexpected(&pair)),
             };
         }
-        match base.chain.is_empty() {
-            true => base.base,
-            false => ASTNode::call_chain(base, r),
+        // prefix binds looser than the call chain, `-a.b` is `-(a.b)`
+        Ok(Operand { prefix, base: self.finish_chain(base, r), range: r })
+    }
+
+    fn finish_chain(&self, chain: CallChain, r: Range) -> ASTNode {
+        match chain.chain.is_empty() {
+            true => chain.base,
+            false => ASTNode::call_chain(chain, r),
         }
     }
 
-    fn parse_pattern(&self, pairs: Pair<Rule>) -> ASTNode {
-        let pair = pairs.into_inner().nth(0).unwrap();
-        match pair.as_rule() {
-            Rule::SYMBOL => self.parse_symbol(pair),
-            _ => debug_cases!(pair),
+    fn parse_pattern(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let is_list = pairs.as_str().starts_with('[');
+        let mut symbols = vec![];
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Comma => continue,
+                Rule::SYMBOL => symbols.push(self.parse_symbol(pair)),
+                Rule::pattern => symbols.push(self.parse_pattern(pair)?),
+                Rule::term => symbols.push(self.parse_term(pair)?),
+                _ => return Err(self.unexpected(&pair)),
+            };
         }
+        Ok(match (is_list, symbols.len()) {
+            (false, 1) => symbols.remove(0),
+            _ => ASTNode::list(symbols, r),
+        })
     }
 
     fn parse_operation(&self, pairs: Pair<Rule>, kind: &str) -> ASTNode {
@@ -173,179 +216,514 @@ impl ParserConfig {
         ASTNode::operation(op, kind, r)
     }
 
-    fn parse_assign(&self, pairs: Pair<Rule>) -> ASTNode {
-        let mut terms = pairs.into_inner();
-        let pattern = self.parse_pattern(terms.next().unwrap());
-        let expr = self.parse_expr(terms.next().unwrap());
-        unreachable!("{:?}\n{:?}", pattern, expr)
+    fn parse_assign(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let mut kind = AssignKind::Set;
+        let (mut pattern, mut value) = Default::default();
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Set => continue,
+                Rule::assign_word => match pair.as_str() {
+                    "var" => kind = AssignKind::Var,
+                    _ => kind = AssignKind::Let,
+                },
+                Rule::pattern | Rule::pattern_bare => pattern = self.parse_pattern(pair)?,
+                Rule::expr => value = self.parse_expr(pair)?,
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::assign(kind, pattern, value, r))
+    }
+
+    fn parse_define(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let mut parameters = vec![];
+        let (mut name, mut body) = Default::default();
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::define_word | Rule::Set => continue,
+                Rule::SYMBOL => name = self.parse_symbol(pair),
+                Rule::define_terms => {
+                    for term in pair.into_inner() {
+                        match term.as_rule() {
+                            Rule::Comma => continue,
+                            _ => parameters.push(self.parse_define_pair(term)?),
+                        }
+                    }
+                }
+                Rule::block => body = self.parse_block(pair)?,
+                Rule::statement => body = self.parse_statement(pair)?,
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::function_define(name, parameters, body, r))
     }
 
-    fn parse_dot_call(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_control(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let (mut kind, mut value) = (ControlKind::Pass, None);
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Control => kind = ControlKind::from(pair.as_str()),
+                Rule::expr => value = Some(self.parse_expr(pair)?),
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::control_flow(kind, value, r))
+    }
+
+    fn parse_class(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let mut name = ASTNode::default();
+        let mut body = ASTNode::block(vec![], r);
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::SYMBOL => name = self.parse_identifier(pair),
+                Rule::block => body = self.parse_block(pair)?,
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::class_statement(name, body, r))
+    }
+
+    fn parse_annotation(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let mut annotations = vec![];
+        let mut statement = ASTNode::default();
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::annotation_call => {
+                    for inner in pair.into_inner() {
+                        match inner.as_rule() {
+                            Rule::annotation_item => annotations.push(self.parse_annotation_item(inner)?),
+                            Rule::annotation_list => {
+                                for item in inner.into_inner().filter(|e| e.as_rule() == Rule::annotation_item) {
+                                    annotations.push(self.parse_annotation_item(item)?)
+                                }
+                            }
+                            _ => return Err(self.unexpected(&inner)),
+                        }
+                    }
+                }
+                Rule::statement => statement = self.parse_statement(pair)?,
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::annotation_statement(annotations, statement, r))
+    }
+    fn parse_annotation_item(&self, pairs: Pair<Rule>) -> Result<Annotation> {
+        let range = self.get_position(&pairs);
+        let mut name = ASTNode::default();
+        let mut arguments = CallApply::default();
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Symbol => name = self.parse_namespace(pair)?,
+                Rule::apply => {
+                    if let ASTKind::CallApply(apply) = self.parse_apply(pair)?.kind {
+                        arguments = *apply
+                    }
+                }
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(Annotation { name, arguments, range })
+    }
+
+    fn parse_extend(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let (mut target, mut body) = Default::default();
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Colon => continue,
+                Rule::Symbol => target = self.parse_namespace(pair)?,
+                Rule::block => body = self.parse_block(pair)?,
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::extend_statement(target, body, r))
+    }
+
+    fn parse_import(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let mut relative = 0;
+        let mut items = vec![];
+        for pair in pairs.into_inner() {
+            let prefix = ModulePath::Symbol { relative, segments: vec![] };
+            match pair.as_rule() {
+                Rule::Dot => relative += 1,
+                Rule::use_alias => items.push(self.parse_use_alias(pair, prefix)?),
+                Rule::use_module_select => self.parse_use_select(pair, prefix, &mut items)?,
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::import_statement(items, r))
+    }
+    fn parse_use_alias(&self, pairs: Pair<Rule>, mut path: ModulePath) -> Result<ImportItem> {
+        let range = self.get_position(&pairs);
+        let mut names = vec![];
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Dot | Rule::Proportion => continue,
+                Rule::String => path = ModulePath::File(self.parse_string(pair)?.as_string()),
+                Rule::SYMBOL => names.push(pair.as_str().to_string()),
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        let alias = names.pop();
+        if let ModulePath::Symbol { segments, .. } = &mut path {
+            segments.extend(names)
+        }
+        Ok(ImportItem { path, alias, glob: false, range })
+    }
+    /// Items in `a::{b, c::*}` are flattened to `a::b` and `a::c::*`.
+    fn parse_use_select(&self, pairs: Pair<Rule>, mut path: ModulePath, items: &mut Vec<ImportItem>) -> Result<()> {
+        let range = self.get_position(&pairs);
+        let mut glob = false;
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Dot | Rule::Proportion => continue,
+                Rule::SYMBOL => {
+                    if let ModulePath::Symbol { segments, .. } = &mut path {
+                        segments.push(pair.as_str().to_string())
+                    }
+                }
+                Rule::Star => glob = true,
+                Rule::module_block => {
+                    for tuple in pair.into_inner().filter(|e| e.as_rule() == Rule::module_tuple) {
+                        for inner in tuple.into_inner() {
+                            match inner.as_rule() {
+                                Rule::use_alias => items.push(self.parse_use_alias(inner, path.to_owned())?),
+                                Rule::use_module_select => self.parse_use_select(inner, path.to_owned(), items)?,
+                                _ => return Err(self.unexpected(&inner)),
+                            }
+                        }
+                    }
+                    return Ok(());
+                }
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        items.push(ImportItem { path, alias: None, glob, range });
+        Ok(())
+    }
+
+    fn parse_define_pair(&self, pairs: Pair<Rule>) -> Result<(ASTNode, Option<ASTNode>)> {
+        let mut name = ASTNode::default();
+        let mut default = None;
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Set => continue,
+                Rule::SYMBOL => name = self.parse_symbol(pair),
+                Rule::expr => default = Some(self.parse_expr(pair)?),
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok((name, default))
+    }
+
+    fn parse_apply(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let mut arguments = vec![];
+        let mut keywords = vec![];
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Comma => continue,
+                Rule::apply_kv => {
+                    let mut key = None;
+                    for inner in pair.into_inner() {
+                        match inner.as_rule() {
+                            Rule::Set => continue,
+                            Rule::SYMBOL => key = Some(self.parse_symbol(inner)),
+                            Rule::expr => match key.take() {
+                                Some(k) => keywords.push((k, self.parse_expr(inner)?)),
+                                None => arguments.push(self.parse_expr(inner)?),
+                            },
+                            _ => return Err(self.unexpected(&inner)),
+                        };
+                    }
+                }
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::call_apply(arguments, keywords, r))
+    }
+
+    fn parse_dot_call(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
         let r = self.get_position(&pairs);
         let mut positive = true;
+        let mut symbol = None;
+        let mut apply = None;
         for pair in pairs.into_inner() {
             match pair.as_rule() {
                 Rule::Dot => continue,
-                Rule::Symbol => continue,
-                Rule::apply => continue,
+                Rule::Symbol => symbol = Some(self.parse_namespace(pair)?),
+                Rule::apply => apply = Some(self.parse_apply(pair)?),
                 Rule::Minus => positive = false,
                 Rule::Plus => positive = true,
-                Rule::Integer => return ASTNode::call_index(pair.as_str(), positive, r),
-                _ => debug_cases!(pair),
+                Rule::Integer => return Ok(ASTNode::call_index(pair.as_str(), positive, r)),
+                _ => return Err(self.unexpected(&pair)),
             };
         }
-        unreachable!()
+        match symbol {
+            Some(s) => Ok(ASTNode::call_dot(s, apply, r)),
+            None => Err(self.missing(r, "Symbol")),
+        }
     }
 }
 
 impl ParserConfig {
-    fn parse_data(&self, pairs: Pair<Rule>) -> ASTNode {
-        let pair = pairs.into_inner().nth(0).unwrap();
-        match pair.as_rule() {
-            Rule::template => self.parse_template(pair),
-            Rule::list => self.parse_list(pair),
-            Rule::String => self.parse_string(pair),
-            Rule::Number => self.parse_number(pair),
-            Rule::Symbol => self.parse_namespace(pair),
+    fn parse_data(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let pair = self.first_inner(pairs)?;
+        Ok(match pair.as_rule() {
+            Rule::template => self.parse_template(pair)?,
+            Rule::list => self.parse_list(pair)?,
+            Rule::dict => self.parse_dict(pair)?,
+            Rule::String => self.parse_string(pair)?,
+            Rule::Number => self.parse_number(pair)?,
+            Rule::Symbol => self.parse_namespace(pair)?,
             Rule::SpecialValue => self.parse_special(pair),
 
-            _ => debug_cases!(pair),
-        }
+            _ => return Err(self.unexpected(&pair)),
+        })
     }
-    fn parse_template(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_template(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let _depth = self.nest(&pairs)?;
         let r = self.get_position(&pairs);
-        let mut tag = ASTNode::default();
+        let mut tag = None;
         let mut attributes = vec![];
         let mut arguments = vec![];
         let mut children = vec![];
-        let pair = pairs.into_inner().nth(0).unwrap();
+        let pair = self.first_inner(pairs)?;
         let mut template = match pair.as_rule() {
             Rule::SelfClose => Template::self_close(),
             Rule::HTMLBad => Template::html_bad(),
             Rule::OpenClose => Template::open_close(),
-            _ => debug_cases!(pair),
+            Rule::SDLOpenClose => Template::sdl_special(),
+            Rule::Fragment => Template::fragment(),
+            Rule::SDLFragment => Template::sdl_fragment(),
+            Rule::EmptyTemplate => Template::empty(),
+            // comments and doctype are written out as is
+            Rule::HTMLComment | Rule::HtmlDTD => return Ok(ASTNode::string_escaped(pair.as_str().to_string(), r)),
+            _ => return Err(self.unexpected(&pair)),
         };
         for inner in pair.into_inner() {
             match inner.as_rule() {
-                Rule::Symbol => tag = self.parse_namespace(inner),
-                Rule::HTMLBadTag => tag = self.parse_symbol(inner),
-                Rule::text_mode => children.push(self.parse_text_mode(inner)),
+                Rule::Symbol => tag = Some(self.parse_namespace(inner)?),
+                Rule::HTMLBadTag => tag = Some(self.parse_symbol(inner)),
+                Rule::text_mode => {
+                    let name = tag.as_ref().map(|t| t.as_string()).unwrap_or_default();
+                    children.extend(self.parse_text_mode(inner, &name)?)
+                }
+                Rule::statement => children.push(self.parse_statement(inner)?),
 
-                Rule::BadSymbol => attributes.push(self.parse_string(inner)),
-                Rule::html_pair => arguments.push(self.parse_pair(inner)),
-                _ => debug_cases!(inner),
+                Rule::BadSymbol => attributes.push(self.parse_attribute_name(inner)),
+                Rule::html_pair => arguments.push(self.parse_pair(inner)?),
+                _ => return Err(self.unexpected(&inner)),
             };
         }
-        template.set_tag(tag);
+        // `</tag>` is matched by `POP`, so it is located from the end of the template
+        if let (TemplateKind::OpenCloseTemplate | TemplateKind::SDLSpecialTemplate, Some(tag)) = (&template.kind, &tag) {
+            let width = tag.as_string().chars().count() as u64 + 3;
+            let mut end = tag.to_owned();
+            end.range = Range { start: Position { line: r.end.line, character: r.end.character.saturating_sub(width) }, end: r.end };
+            template.set_end(end)
+        }
+        if let Some(tag) = tag {
+            template.set_tag(tag);
+        }
         template.set_attributes(attributes);
         template.set_arguments(arguments);
-        return ASTNode::template(template, r);
+        template.set_children(children);
+        Ok(ASTNode::template(template, r))
     }
-    fn parse_text_mode(&self, pairs: Pair<Rule>) -> ASTNode {
-        let r = self.get_position(&pairs);
+    /// Text, entities, interpolations and nested templates in the body of `<tag>`, in order.
+    ///
+    /// See [`TextBuffer`] for how raw text is escaped and collapsed.
+    fn parse_text_mode(&self, pairs: Pair<Rule>, tag: &str) -> Result<Vec<ASTNode>> {
         let mut terms = vec![];
-        let mut text = vec![];
+        let mut text = TextBuffer::new(tag);
         for pair in pairs.into_inner() {
             match pair.as_rule() {
-                Rule::WHITESPACE => continue,
-                Rule::statement => terms.push(self.parse_statement(pair)),
-                Rule::HTMLText => text.push(pair.as_str()),
-                _ => debug_cases!(pair),
+                Rule::interpolation => {
+                    text.flush(&mut terms);
+                    for inner in pair.into_inner() {
+                        terms.push(self.parse_statement(inner)?)
+                    }
+                }
+                Rule::template => {
+                    text.flush(&mut terms);
+                    terms.push(self.parse_template(pair)?)
+                }
+                Rule::HTMLEscape => text.push_escaped(pair.as_str(), self.get_position(&pair)),
+                Rule::HTMLText => text.push_text(pair.as_str(), self.get_position(&pair)),
+                _ => return Err(self.unexpected(&pair)),
             };
         }
-        ASTNode::block(terms, r)
+        text.finish(&mut terms);
+        Ok(terms)
     }
 
-    fn parse_list(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_list(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
         let r = self.get_position(&pairs);
         let mut terms = vec![];
         for pair in pairs.into_inner() {
             match pair.as_rule() {
                 Rule::WHITESPACE => continue,
                 Rule::Comma => continue,
-                Rule::expr => terms.push(self.parse_expr(pair)),
-                _ => debug_cases!(pair),
+                Rule::expr => terms.push(self.parse_expr(pair)?),
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::list(terms, r))
+    }
+    fn parse_dict(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let mut dict = IndexMap::new();
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Comma => continue,
+                Rule::dict_pair => {
+                    let (key, value) = self.parse_dict_pair(pair)?;
+                    dict.insert(key, value);
+                }
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok(ASTNode::dict(dict, r))
+    }
+    fn parse_dict_pair(&self, pairs: Pair<Rule>) -> Result<(String, ASTNode)> {
+        let (mut key, mut value) = Default::default();
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Colon => continue,
+                Rule::SYMBOL => key = pair.as_str().to_string(),
+                Rule::Integer => {
+                    key = ASTNode::integer(pair.as_str(), 10, Default::default()).kind.as_key().unwrap_or_else(|| pair.as_str().to_string())
+                }
+                Rule::String => key = match self.parse_string(pair)? {
+                    ASTNode { kind: ASTKind::String(s), .. } => s,
+                    node => node.as_string(),
+                },
+                Rule::expr => value = self.parse_expr(pair)?,
+                _ => return Err(self.unexpected(&pair)),
+            };
+        }
+        Ok((key, value))
+    }
+    fn parse_slice(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
+        let r = self.get_position(&pairs);
+        let mut indexes = vec![];
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::Comma => continue,
+                Rule::index => indexes.push(self.parse_index(pair)?),
+                _ => return Err(self.unexpected(&pair)),
             };
         }
-        ASTNode::list(terms, r)
+        Ok(ASTNode::call_slice(indexes, r))
+    }
+    fn parse_index(&self, pairs: Pair<Rule>) -> Result<SliceIndex> {
+        let pair = self.first_inner(pairs)?;
+        Ok(match pair.as_rule() {
+            Rule::expr => SliceIndex::Index(self.parse_expr(pair)?),
+            Rule::index_range | Rule::index_step => {
+                // `start:end:step`, which part an expression belongs to depends on the colons before it
+                let mut bounds: [Option<ASTNode>; 3] = Default::default();
+                let mut colons = 0;
+                for inner in pair.into_inner() {
+                    match inner.as_rule() {
+                        Rule::Colon => colons += 1,
+                        Rule::expr => bounds[colons] = Some(self.parse_expr(inner)?),
+                        _ => return Err(self.unexpected(&inner)),
+                    };
+                }
+                let [start, end, step] = bounds;
+                SliceIndex::Range { start, end, step }
+            }
+            _ => return Err(self.unexpected(&pair)),
+        })
     }
-    fn parse_pair(&self, pairs: Pair<Rule>) -> (ASTNode, ASTNode) {
+    fn parse_pair(&self, pairs: Pair<Rule>) -> Result<(ASTNode, ASTNode)> {
         let (mut key, mut value) = Default::default();
         for pair in pairs.into_inner() {
             match pair.as_rule() {
                 Rule::Set => continue,
-                Rule::BadSymbol => key = self.parse_string(pair),
-                Rule::term => value = self.parse_term(pair),
-                _ => debug_cases!(pair),
+                Rule::BadSymbol => key = self.parse_attribute_name(pair),
+                Rule::term => value = self.parse_term(pair)?,
+                _ => return Err(self.unexpected(&pair)),
             };
         }
-        (key, value)
+        Ok((key, value))
+    }
+    fn parse_attribute_name(&self, pairs: Pair<Rule>) -> ASTNode {
+        let r = self.get_position(&pairs);
+        ASTNode::string(pairs.as_str().to_string(), r)
     }
-    fn parse_namespace(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_namespace(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
         let r = self.get_position(&pairs);
         let mut value = vec![];
         for pair in pairs.into_inner() {
             match pair.as_rule() {
-                Rule::SYMBOL => value.push(self.parse_string(pair)),
-                _ => debug_cases!(pair),
+                Rule::Proportion => continue,
+                Rule::SYMBOL => value.push(self.parse_identifier(pair)),
+                Rule::namespace => value.extend(self.parse_namespace(pair)?.as_symbol_path()),
+                _ => return Err(self.unexpected(&pair)),
             };
         }
-        ASTNode::symbol(value, r)
+        Ok(ASTNode::symbol(value, r))
     }
     fn parse_symbol(&self, pairs: Pair<Rule>) -> ASTNode {
         let r = self.get_position(&pairs);
-        let value = vec![self.parse_string(pairs)];
+        let value = vec![self.parse_identifier(pairs)];
         ASTNode::symbol(value, r)
     }
+    fn parse_identifier(&self, pairs: Pair<Rule>) -> ASTNode {
+        let r = self.get_position(&pairs);
+        ASTNode::string(pairs.as_str().to_string(), r)
+    }
 
-    fn parse_string(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_string(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
         let r = self.get_position(&pairs);
         let mut is_pure_string = true;
+        let mut handler = None;
         let mut block = vec![];
         let mut _marks = 0;
         let mut buffer = String::new();
         for pair in pairs.into_inner() {
             match pair.as_rule() {
-                Rule::StringEmpty => return ASTNode::string(String::new(), r),
+                Rule::SYMBOL => handler = Some(self.parse_symbol(pair)),
+                Rule::StringEmpty => break,
                 Rule::S1 | Rule::S2 | Rule::S3 | Rule::S4 => _marks += 1,
-                Rule::NS1 | Rule::NS2 | Rule::NS3 | Rule::NS4 => {
-                    let text = pair.as_str();
-                    match text {
-                        "{{" => buffer.push('{'),
-                        "}}" => buffer.push('}'),
-                        "\\n" => buffer.push('\n'),
-                        _ => match text.starts_with('\\') {
-                            true => buffer.push_str(&text[1..text.len()]),
-                            false => buffer.push_str(text),
-                        },
-                    }
-                }
+                Rule::NS1 | Rule::NS2 | Rule::NS3 | Rule::NS4 => buffer.push_str(pair.as_str()),
+                // invalid escapes are reported by `check_escapes`
+                Rule::StringEscape => buffer.push_str(&unescape(pair.as_str()).unwrap_or_default()),
                 Rule::expr => {
                     is_pure_string = false;
-                    if !buffer.is_empty() {
+                    // pieces and values alternate for the handler
+                    if !buffer.is_empty() || handler.is_some() {
                         block.push(ASTNode::string(buffer, Default::default()));
                         buffer = String::new()
                     }
-                    block.push(self.parse_expr(pair))
+                    block.push(self.parse_expr(pair)?)
                 }
-                _ => debug_cases!(pair),
+                _ => return Err(self.unexpected(&pair)),
             };
         }
-        match is_pure_string {
-            true => ASTNode::string(buffer, r),
-            false => {
-                if !buffer.is_empty() {
+        Ok(match (is_pure_string, handler) {
+            (true, None) => ASTNode::string(buffer, r),
+            (_, handler) => {
+                if !buffer.is_empty() || handler.is_some() {
                     block.push(ASTNode::string(buffer, Default::default()));
                 }
-                ASTNode::string_expression(block, Default::default(), r)
+                ASTNode::string_expression(block, handler, r)
             }
-        }
+        })
     }
 
-    fn parse_number(&self, pairs: Pair<Rule>) -> ASTNode {
+    fn parse_number(&self, pairs: Pair<Rule>) -> Result<ASTNode> {
         let r = self.get_position(&pairs);
-        let pair = pairs.into_inner().nth(0).unwrap();
-        match pair.as_rule() {
+        let pair = self.first_inner(pairs)?;
+        Ok(match pair.as_rule() {
             Rule::Integer => ASTNode::integer(pair.as_str(), 10, r),
             Rule::Decimal => ASTNode::decimal(pair.as_str(), 10, r),
             Rule::DecimalBad => {
@@ -364,8 +742,8 @@ impl ParserConfig {
                     _ => ASTNode::decimal(pair.as_str(), 16, r),
                 }
             }
-            _ => unreachable!(),
-        }
+            _ => return Err(self.unexpected(&pair)),
+        })
     }
     fn parse_special(&self, pairs: Pair<Rule>) -> ASTNode {
         let r = self.get_position(&pairs);
@@ -1,18 +1,41 @@
+use sdl_ast::{ast::Range, ASTNode};
 use sdl_pest::{
     Assoc::{Left, Right},
-    Operator, PrecClimber, Rule,
+    Op, PrattParser, Rule,
 };
-use std::lazy::SyncLazy;
+use std::sync::LazyLock;
 
+/// Operator precedence, from the loosest to the tightest.
 #[rustfmt::skip]
-pub static PREC_CLIMBER: SyncLazy<PrecClimber<Rule>> = SyncLazy::new(|| {
+pub static PRATT_PARSER: LazyLock<PrattParser<Rule>> = LazyLock::new(|| {
     use Rule::*;
-    //TODO: use macro
-    PrecClimber::new(vec![
-        Operator::new(Set, Left),
-        Operator::new(Logical, Left),
-        Operator::new(Additive, Left),
-        Operator::new(Power, Right),
-        Operator::new(Dot, Left)
-    ])
+    PrattParser::new()
+        .op(Op::infix(Set, Right))
+        .op(Op::infix(LogicOr, Left))
+        .op(Op::infix(LogicAnd, Left))
+        .op(Op::infix(Compare, Left))
+        .op(Op::infix(Additive, Left))
+        .op(Op::infix(Multiplied, Left))
+        .op(Op::infix(Power, Right))
 });
+
+/// An operand of an infix operator, prefix operators are applied once it is known whether it is the base of `^`.
+pub struct Operand {
+    /// Prefix operators from the outermost to the innermost.
+    pub prefix: Vec<ASTNode>,
+    pub base: ASTNode,
+    pub range: Range,
+}
+
+impl Operand {
+    pub fn new(base: ASTNode) -> Self {
+        Self { prefix: vec![], range: base.range, base }
+    }
+    pub fn finish(self) -> ASTNode {
+        let mut out = self.base;
+        for op in self.prefix.into_iter().rev() {
+            out = ASTNode::prefix_expression(op, out, self.range)
+        }
+        out
+    }
+}
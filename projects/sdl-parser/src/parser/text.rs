@@ -0,0 +1,89 @@
+use super::*;
+use sdl_ast::escape_text;
+
+/// Elements whose text is neither escaped nor collapsed.
+static RAW_TEXT_TAGS: &[&str] = &["script", "style"];
+
+/// Elements whose whitespace is kept as is.
+static PREFORMATTED_TAGS: &[&str] = &["pre", "textarea"];
+
+/// Raw text between tags, it becomes a single `HTMLText` node once an interpolation or a template is met.
+pub(super) struct TextBuffer<'t> {
+    tag: &'t str,
+    text: String,
+    range: Option<Range>,
+}
+
+impl<'t> TextBuffer<'t> {
+    pub fn new(tag: &'t str) -> Self {
+        Self { tag, text: String::new(), range: None }
+    }
+    /// Entities are already valid html and are kept as written.
+    pub fn push_escaped(&mut self, text: &str, r: Range) {
+        self.text.push_str(text);
+        self.extend_range(r);
+    }
+    /// Stray `<`, `>` and `&` are escaped, except inside `<script>` and `<style>`.
+    pub fn push_text(&mut self, text: &str, r: Range) {
+        match RAW_TEXT_TAGS.contains(&self.tag) {
+            true => self.text.push_str(text),
+            false => self.text.push_str(&escape_text(text)),
+        }
+        self.extend_range(r);
+    }
+    fn extend_range(&mut self, r: Range) {
+        match &mut self.range {
+            Some(s) => s.end = r.end,
+            None => self.range = Some(r),
+        }
+    }
+    /// Move the buffered text into `terms`, text that collapses to nothing is dropped.
+    pub fn flush(&mut self, terms: &mut Vec<ASTNode>) {
+        self.flush_text(terms, false)
+    }
+    /// Flush the text at the end of the body.
+    pub fn finish(mut self, terms: &mut Vec<ASTNode>) {
+        self.flush_text(terms, true)
+    }
+    fn flush_text(&mut self, terms: &mut Vec<ASTNode>, at_end: bool) {
+        let text = std::mem::take(&mut self.text);
+        let range = match self.range.take() {
+            Some(s) => s,
+            None => return,
+        };
+        let text = match RAW_TEXT_TAGS.contains(&self.tag) || PREFORMATTED_TAGS.contains(&self.tag) {
+            true => text,
+            false => collapse_whitespace(&text, terms.is_empty(), at_end),
+        };
+        if !text.is_empty() {
+            terms.push(ASTNode::string_escaped(text, range))
+        }
+    }
+}
+
+/// Every run of whitespace becomes a single space as html does, except that a run containing a newline
+/// at the start or the end of the body is removed, so indentation inside tags never reaches the output.
+fn collapse_whitespace(text: &str, at_start: bool, at_end: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    // whether the pending run of whitespace contains a newline
+    let mut space: Option<bool> = None;
+    for c in text.chars() {
+        if c.is_ascii_whitespace() {
+            space = Some(space.unwrap_or(false) || c == '\n');
+            continue;
+        }
+        if let Some(newline) = space.take() {
+            if !(newline && at_start && out.is_empty()) {
+                out.push(' ')
+            }
+        }
+        out.push(c)
+    }
+    match space {
+        Some(true) if at_end => (),
+        Some(true) if at_start && out.is_empty() => (),
+        Some(_) => out.push(' '),
+        None => (),
+    }
+    out
+}
@@ -0,0 +1,108 @@
+use super::*;
+use sdl_ast::{ASTNode, SDLContextConfig, WarningLevel};
+use std::{cell::RefCell, rc::Rc};
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    decorator,
+];
+
+#[test]
+fn annotation_expression() {
+    assert_eq!(render("def tag(value, name) { [name, value] }\n#tag('x') 5").unwrap(), "[\"x\", 5]")
+}
+
+#[test]
+fn annotation_host() {
+    let seen = Rc::new(RefCell::new(vec![]));
+    let mut ctx = SDLContext::default();
+    let record = seen.clone();
+    ctx.register_annotation("inline", move |target, _| {
+        record.borrow_mut().push(target.name.unwrap_or_default());
+        Ok(target.value)
+    });
+    let out = render_with("#inline\ndef Card(title) { <b>{{ title }}</b> }\n<Card title='a'/>", &mut ctx).unwrap();
+    assert_eq!(out, "<b>a</b>");
+    let seen = seen.borrow().to_owned();
+    assert_eq!(seen, ["Card"])
+}
+
+#[test]
+fn deprecated_warning() {
+    let mut ctx = SDLContext::default();
+    let out = render_with("#deprecated('use `new` instead')\ndef old(x) { x }\n[old(1), old(2)]", &mut ctx).unwrap();
+    assert_eq!(out, "[1, 2]");
+    let warnings: Vec<_> = ctx.take_warnings().iter().map(|e| e.to_string()).collect();
+    assert_eq!(warnings, [
+        "DeprecationWarning: Function `old` is deprecated, use `new` instead\n--> 3:5",
+        "DeprecationWarning: Function `old` is deprecated, use `new` instead\n--> 3:13",
+    ])
+}
+
+#[test]
+fn deprecated_released() {
+    let marker = Rc::new(());
+    let mut ctx = SDLContext::default();
+    let held = marker.clone();
+    ctx.register_raw_fn("marker", move |_, _| Ok(ASTNode::from(Rc::strong_count(&held))));
+    let out = render_with("#deprecated\ndef old(x) { x }\n#deprecated\nclass P { x }\nold(P(1)).x", &mut ctx).unwrap();
+    assert_eq!(out, "1");
+    assert_eq!(ctx.take_warnings().len(), 2);
+    drop(ctx);
+    assert_eq!(Rc::strong_count(&marker), 1)
+}
+
+#[test]
+fn deprecated_closure() {
+    let out = render("def make(y) {\n    #deprecated\n    def f(x) { x + y }\n    f\n}\nmake(1)(2)").unwrap();
+    assert_eq!(out, "3")
+}
+
+#[test]
+fn deprecated_deny() {
+    let mut ctx = SDLContext::default();
+    ctx.set_config(SDLContextConfig { warnings: WarningLevel::Deny, ..Default::default() });
+    let e = render_with("#deprecated\nclass P { x }\nP(1)", &mut ctx).unwrap_err();
+    assert_eq!(e.to_string(), "DeprecationWarning: Class `P` is deprecated
+--> 3:2")
+}
+
+#[test]
+fn test_skipped() {
+    assert_eq!(render("#test\ndef check() { 1 / 0 }\n1").unwrap(), "1")
+}
+
+#[test]
+fn test_run() {
+    let mut ctx = SDLContext::default();
+    ctx.set_config(SDLContextConfig { run_tests: true, ..Default::default() });
+    let e = render_with("#test\ndef check() { 1 / 0 }\n1", &mut ctx).unwrap_err();
+    assert_eq!(e.to_string(), "ZeroDivisionError: Division by zero
+--> 2:17")
+}
+
+#[test]
+fn error_undefined_annotation() {
+    let e = render("#nope let x = 1").unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `nope` is not defined
+--> 1:2")
+}
+
+#[test]
+fn error_deprecated_value() {
+    let e = render("#deprecated let x = 1").unwrap_err();
+    assert_eq!(e.to_string(), "TypeError: Annotation `deprecated` expected `Function` or `Class`, found `Integer`
+--> 1:2")
+}
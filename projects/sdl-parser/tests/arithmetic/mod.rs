@@ -1,4 +1,5 @@
 use super::*;
+use sdl_ast::SDLContextConfig;
 
 macro_rules! run_test {
     ($($F:ident), +,) => {
@@ -18,4 +19,65 @@ run_test![
     decimal_cast,
     string_join,
     list_join,
-];
\ No newline at end of file
+    number_division,
+    number_power,
+    number_mixed,
+];
+
+#[test]
+fn division_precision() {
+    let mut ctx = SDLContext::default();
+    ctx.set_config(SDLContextConfig { precision: 5, ..Default::default() });
+    let out = render_with("[2 / 3, √2]", &mut ctx).unwrap();
+    assert_eq!(out, "[0.66667, 1.4142]")
+}
+
+#[test]
+fn error_divide_by_zero() {
+    let e = render("[1, 2 / 0]").unwrap_err();
+    assert_eq!(e.to_string(), "ZeroDivisionError: Division by zero
+--> 1:7")
+}
+
+#[test]
+fn error_modulo_by_zero() {
+    let e = render("1.5 % 0.0").unwrap_err();
+    assert_eq!(e.to_string(), "ZeroDivisionError: Division by zero
+--> 1:5")
+}
+
+#[test]
+fn error_negative_root() {
+    let e = render("√(0 - 4)").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Cannot take the square root of a negative number
+--> 1:1")
+}
+
+#[test]
+fn error_operand_type() {
+    let e = render("\"a\" * 2").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Cannot apply `*` to `String` and `Integer`
+--> 1:5")
+}
+
+#[test]
+fn error_operand_position() {
+    let e = render("let x = [1]\n1 + 2 + x").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Cannot apply `+` to `Integer` and `List`
+--> 2:7");
+    let e = render("null ++ 1").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Cannot apply `++` to `Null` and `Integer`
+--> 1:6")
+}
+
+#[test]
+fn power_limit() {
+    let out = render("[1 ^ 3000000, (-1) ^ 3000001, 0 ^ 3000000, 1.0 ^ 3000000]").unwrap();
+    assert_eq!(out, "[1, -1, 0, 1]");
+    let e = render("2 ^ 3000000").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Power is out of range
+--> 1:3");
+    let e = render("1.5 ^ -300000").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Power is out of range
+--> 1:5")
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+//! The call limit is shared by the process, so it is tested in a binary of its own.
+use sdl_parser::ParserConfig;
+
+#[test]
+fn call_limit() {
+    let input = "[1, 2, 3, 4, 5, 6, 7, 8, 9]";
+    assert!(ParserConfig::default().parse(input).is_ok());
+    ParserConfig::set_call_limit(Some(100));
+    let e = ParserConfig::default().parse(input).unwrap_err();
+    assert_eq!(e.to_string(), "SyntaxError[S0005]: Input is too complex to be parsed
+--> 1:2");
+    ParserConfig::set_call_limit(None);
+    assert!(ParserConfig::default().parse(input).is_ok())
+}
@@ -0,0 +1,70 @@
+use super::*;
+use sdl_ast::ASTNode;
+use std::rc::Rc;
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    class_basic,
+    extend_builtin,
+    class_equal,
+];
+
+#[test]
+fn error_missing_field() {
+    let e = render("class P { x; y }\nP(1)").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Class `P` missing required field `y`
+--> 2:2")
+}
+
+#[test]
+fn error_unknown_field() {
+    let e = render("class P { x }\nP(1, z = 2)").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Class `P` has no field `z`
+--> 2:6")
+}
+
+#[test]
+fn error_field_access() {
+    let e = render("class P { x }\nP(1).y").unwrap_err();
+    assert_eq!(e.to_string(), "IndexError: Unable to get index y on type `P`
+--> 2:5")
+}
+
+#[test]
+fn error_extend_value() {
+    let e = render("extend String with { let x = 1 }").unwrap_err();
+    assert_eq!(e.to_string(), "TypeError: Extension of `String` expected methods, found `Integer` `x`
+--> 1:30")
+}
+
+#[test]
+fn error_extend_builtin_method() {
+    let e = render("extend String with {\n    def upper(self) { self }\n}").unwrap_err();
+    assert_eq!(e.to_string(), "TypeError: Method `String.upper` is builtin and cannot be extended
+--> 2:9")
+}
+
+#[test]
+fn class_released() {
+    let marker = Rc::new(());
+    let mut ctx = SDLContext::default();
+    let held = marker.clone();
+    ctx.register_raw_fn("marker", move |_, _| Ok(ASTNode::from(Rc::strong_count(&held))));
+    let input = "class P {\n    x\n    def get(self) { P(self.x) }\n}\nlet p = P(1)\nextend P with {\n    def twice(self) { P(self.x * 2) }\n}\np.twice().get().x";
+    assert_eq!(render_with(input, &mut ctx).unwrap(), "2");
+    assert_eq!(Rc::strong_count(&marker), 2);
+    drop(ctx);
+    assert_eq!(Rc::strong_count(&marker), 1)
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    compare_number,
+    compare_equal,
+    compare_in,
+    logical_basic,
+    keyword_prefix,
+    precedence,
+];
+
+#[test]
+fn logical_short_circuit() {
+    let out = render("[false and undefined, true or undefined]").unwrap();
+    assert_eq!(out, "[false, true]")
+}
+
+#[test]
+fn keyword_prefixed_symbol() {
+    let out = render("let android = 1\nlet y = true\nandroid").unwrap();
+    assert_eq!(out, "1");
+    let out = render("var order = 0\nlet x = false\norder = 5\norder").unwrap();
+    assert_eq!(out, "5")
+}
+
+#[test]
+fn recursion_with_condition() {
+    let out = render("def fact(n) {\n    if n > 1 {n * fact(n - 1)} else {1}\n}\nfact(10)").unwrap();
+    assert_eq!(out, "3628800")
+}
+
+#[test]
+fn error_compare_type() {
+    let e = render("1 < \"2\"").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Cannot compare `Integer` with `String`
+--> 1:3")
+}
+
+#[test]
+fn error_in_type() {
+    let e = render("1 in 2").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Cannot test `Integer` in `Integer`
+--> 1:3")
+}
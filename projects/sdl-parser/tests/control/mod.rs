@@ -0,0 +1,55 @@
+use super::*;
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    return_early,
+    return_loop,
+    break_loop,
+    generator_filter,
+    generator_lazy,
+    return_value,
+    generator_nested,
+];
+
+#[test]
+fn keyword_prefix() {
+    assert_eq!(render("let passed = 1; let returns = 2; passed + returns").unwrap(), "3")
+}
+
+#[test]
+fn generator_value() {
+    assert_eq!(render("def g() { yield 1 }\ng()").unwrap(), "Generator(g)")
+}
+
+#[test]
+fn error_break_outside_loop() {
+    let e = render("def f() { break }\nf()").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: `break` outside of a loop
+--> 1:11")
+}
+
+#[test]
+fn error_break_value() {
+    let e = render("for x in [1] { break x }").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: `break` does not take a value
+--> 1:22")
+}
+
+#[test]
+fn error_yield_outside_generator() {
+    let e = render("let f = 1\nyield f").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: `yield` outside of a generator
+--> 2:1")
+}
@@ -15,4 +15,46 @@ macro_rules! run_test {
 
 run_test![
     let_bind,
+    let_shadow,
+    let_destructure,
+    let_nested,
+    var_bind,
 ];
+
+#[test]
+fn error_let_reassign() {
+    let e = render("let x = 1;\nx = 2").unwrap_err();
+    assert_eq!(e.to_string(), "AssignError: Cannot assign twice to immutable variable `x`
+--> 2:1")
+}
+
+#[test]
+fn error_assign_undefined() {
+    let e = render("x = 2").unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `x` is not defined
+--> 1:1")
+}
+
+#[test]
+fn error_unpack_length() {
+    let e = render("let (a, b) = [1, 2, 3]").unwrap_err();
+    assert_eq!(e.to_string(), "PatternError: Unable to unpack 3 items into 2 variables
+--> 1:5")
+}
+
+#[test]
+fn error_unpack_type() {
+    let e = render("let a, b = 1").unwrap_err();
+    assert_eq!(e.to_string(), "PatternError: Unable to unpack type `Integer` into 2 variables
+--> 1:12")
+}
+
+#[test]
+fn error_pattern_nested() {
+    let e = render("let (a, [b, 1]) = [1, [2, 3]]").unwrap_err();
+    assert_eq!(e.to_string(), "PatternError: Unable to assign to type `Integer`
+--> 1:13");
+    let e = render("let [a, b.c] = [1, 2]").unwrap_err();
+    assert_eq!(e.to_string(), "PatternError: Unable to assign to type `CallChain`
+--> 1:9")
+}
@@ -0,0 +1,52 @@
+use super::*;
+use sdl_ast::{ast::IndexMap, ASTNode};
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    dict_literal,
+    dict_access,
+    dict_merge,
+    dict_method,
+    dict_for,
+    dict_integer_key,
+];
+
+#[test]
+fn dict_from_host() {
+    let mut ctx = SDLContext::default();
+    let mut user = IndexMap::new();
+    user.insert(String::from("name"), ASTNode::from("Alice"));
+    user.insert(String::from("age"), ASTNode::from(30));
+    ctx.insert("user", user);
+    let out = render_with("[user.name, user[\"age\"] + 1]", &mut ctx).unwrap();
+    assert_eq!(out, "[\"Alice\", 31]")
+}
+
+#[test]
+fn error_index_type() {
+    let e = render("[1, 2][\"a\"]").unwrap_err();
+    assert_eq!(e.to_string(), "IndexError: Unable to get index \"a\" on type `List`
+--> 1:7")
+}
+
+#[test]
+fn error_key_type() {
+    let e = render("[1] in {a: 1}").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Cannot test `List` in `Dict`
+--> 1:5");
+    let e = render("{a: 1}.get(null)").unwrap_err();
+    assert_eq!(e.to_string(), "TypeError: Method `Dict.get` expected `String` for argument 1, found `Null`
+--> 1:7")
+}
@@ -19,6 +19,12 @@ run_test![
     for_if_guard,
     for_else_guard,
     for_if_else_guard,
+    for_tuple_enumerate,
+    for_dict,
+    for_range,
+    for_loop_info,
+    for_generator_guard,
+    for_guard_order,
 ];
 
 #[test]
@@ -26,4 +32,25 @@ fn error_for_in_decimal() {
     let e = render("for i in 0.0 {}").unwrap_err();
     assert_eq!(e.to_string(), "IteratorError: Type `Decimal` is not an iterable element
 --> 1:10")
-}
\ No newline at end of file
+}
+
+#[test]
+fn error_for_unpack() {
+    let e = render("for (a, b) in [[1, 2, 3]] { a }").unwrap_err();
+    assert_eq!(e.to_string(), "PatternError: Unable to unpack 3 items into 2 variables
+--> 1:5")
+}
+
+#[test]
+fn error_range_step() {
+    let e = render("for i in 1.to(3, 0) { i }").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Method `Integer.to` expected a non-zero step
+--> 1:11")
+}
+
+#[test]
+fn error_range_limit() {
+    let e = render("for i in 1.to(10 ^ 9) { i }").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Method `Integer.to` can give at most 1000000 items, found 1000000000
+--> 1:11")
+}
@@ -0,0 +1,95 @@
+use super::*;
+use sdl_ast::{ASTNode, SDLContextConfig};
+use std::rc::Rc;
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    define_call,
+    define_default,
+    closure_capture,
+    late_binding,
+];
+
+#[test]
+fn error_not_callable() {
+    let e = render("1(2)").unwrap_err();
+    assert_eq!(e.to_string(), "CallError: Type `Integer` is not callable
+--> 1:2")
+}
+
+#[test]
+fn error_too_many_arguments() {
+    let e = render("def f(a) { a }\nf(1, 2)").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Function `f` takes 1 arguments but 2 were given
+--> 2:2")
+}
+
+#[test]
+fn error_missing_argument() {
+    let e = render("f(a, b) = a\nf(1)").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Function `f` missing required argument `b`
+--> 2:2")
+}
+
+#[test]
+fn error_unexpected_keyword() {
+    let e = render("f(a) = a\nf(1, c = 2)").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Function `f` got an unexpected keyword argument `c`
+--> 2:6")
+}
+
+#[test]
+fn error_duplicate_keyword() {
+    let e = render("f(a) = a\nf(1, a = 2)").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Function `f` got multiple values for argument `a`
+--> 2:6")
+}
+
+#[test]
+fn deep_recursion() {
+    let out = render("def count(n) { if n == 0 { 0 } else { 1 + count(n - 1) } }\ncount(999)").unwrap();
+    assert_eq!(out, "999")
+}
+
+#[test]
+fn error_recursion_limit() {
+    let e = render("def f(x) { f(x) }\nf(1)").unwrap_err();
+    assert_eq!(e.to_string(), "RecursionError: Maximum call depth of 1000 exceeded
+--> 1:13")
+}
+
+#[test]
+fn error_recursion_limit_config() {
+    let mut ctx = SDLContext::default();
+    ctx.set_config(SDLContextConfig { max_call_depth: 10, ..Default::default() });
+    let out = render_with("def count(n) { if n == 0 { 0 } else { 1 + count(n - 1) } }\ncount(9)", &mut ctx).unwrap();
+    assert_eq!(out, "9");
+    let e = render_with("count(10)", &mut ctx).unwrap_err();
+    assert_eq!(e.to_string(), "RecursionError: Maximum call depth of 10 exceeded
+--> 1:48")
+}
+
+#[test]
+fn scope_released() {
+    let marker = Rc::new(());
+    let mut ctx = SDLContext::default();
+    let held = marker.clone();
+    ctx.register_raw_fn("marker", move |_, _| Ok(ASTNode::from(Rc::strong_count(&held))));
+    let input = "def f(x) { f(x) }\ndef outer() {\n    def inner() { outer }\n    inner\n}\nlet g = outer()\nvar h = f\nh = outer";
+    render_with(input, &mut ctx).unwrap();
+    assert_eq!(Rc::strong_count(&marker), 2);
+    drop(ctx);
+    assert_eq!(Rc::strong_count(&marker), 1)
+}
@@ -1,4 +1,5 @@
 use super::*;
+use sdl_ast::{SDLContextConfig, WarningLevel};
 
 macro_rules! run_test {
     ($($F:ident), +,) => {
@@ -15,8 +16,37 @@ macro_rules! run_test {
 
 run_test![
     if_simple,
+    if_truthy,
+    logic_truthy,
 ];
 
+#[test]
+fn warning_if_lost() {
+    let mut ctx = SDLContext::default();
+    let out = render_with("if false { 1 }\nif 0 { 2 } else if \"\" { 3 }", &mut ctx).unwrap();
+    assert_eq!(out, "");
+    let warnings: Vec<_> = ctx.take_warnings().iter().map(|e| e.to_string()).collect();
+    assert_eq!(warnings, ["IfLostError: If statements are not exhaustive\n--> 1:1", "IfLostError: If statements are not exhaustive\n--> 2:1"]);
+    assert!(ctx.take_warnings().is_empty())
+}
+
+#[test]
+fn warning_allow() {
+    let mut ctx = SDLContext::default();
+    ctx.set_config(SDLContextConfig { warnings: WarningLevel::Allow, ..Default::default() });
+    render_with("if false { 1 }", &mut ctx).unwrap();
+    assert!(ctx.take_warnings().is_empty())
+}
+
+#[test]
+fn warning_deny() {
+    let mut ctx = SDLContext::default();
+    ctx.set_config(SDLContextConfig { warnings: WarningLevel::Deny, ..Default::default() });
+    let e = render_with("let x = 1;\nif x > 1 { 1 }", &mut ctx).unwrap_err();
+    assert_eq!(e.to_string(), "IfLostError: If statements are not exhaustive
+--> 2:1")
+}
+
 //
 // #[test]
 // fn error_for_in_decimal() {
@@ -0,0 +1,148 @@
+use super::*;
+use sdl_ast::{ast::ModulePath, ASTNode, ModuleLoader};
+use sdl_parser::{FileLoader, MemoryLoader};
+use std::{cell::Cell, rc::Rc};
+
+fn site() -> SDLContext {
+    let mut ctx = SDLContext::default();
+    ctx.set_loader(FileLoader::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/import/site")));
+    ctx
+}
+
+#[test]
+fn import_module() {
+    let out = render_with("import lib::math\n[math::double(2), math::pi]", &mut site()).unwrap();
+    assert_eq!(out, "[4, 3]")
+}
+
+#[test]
+fn import_alias() {
+    let out = render_with("import lib::math as m\nimport \"lib/math.sdl\" as n\n[m::pi, n::pi]", &mut site()).unwrap();
+    assert_eq!(out, "[3, 3]")
+}
+
+#[test]
+fn import_select() {
+    let out = render_with("import lib::math::{double, pi as p}\n[double(p)]", &mut site()).unwrap();
+    assert_eq!(out, "[6]")
+}
+
+#[test]
+fn import_glob() {
+    let out = render_with("import lib::math::*\n[double(1), pi]", &mut site()).unwrap();
+    assert_eq!(out, "[2, 3]");
+    let e = render_with("import lib::math::*\n_secret", &mut site()).unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `_secret` is not defined\n--> 2:1")
+}
+
+#[test]
+fn import_relative() {
+    let out = render_with("import lib::ui::twice\n[twice(1)]", &mut site()).unwrap();
+    assert_eq!(out, "[4]")
+}
+
+#[test]
+fn import_memory() {
+    let mut loader = MemoryLoader::default();
+    loader.insert("a/b.sdl", "import ..c::{d::e}\nlet x = e + 1;");
+    loader.insert("c/d.sdl", "let e = 1;");
+    let mut ctx = SDLContext::default();
+    ctx.set_loader(loader);
+    let out = render_with("import a::{b::{x}}\n[x]", &mut ctx).unwrap();
+    assert_eq!(out, "[2]")
+}
+
+#[test]
+fn import_host() {
+    let mut loader = MemoryLoader::default();
+    loader.insert("ui.sdl", "#inline\ndef greet(name) { hello(name) ++ upper'!' }");
+    let mut ctx = SDLContext::default();
+    ctx.set_loader(loader);
+    ctx.register_fn("hello", |name: String| Ok(format!("Hello, {}", name)));
+    ctx.register_annotation("inline", |target, _| Ok(target.value));
+    ctx.register_string_handler("upper", |s, _| Ok(ASTNode::string(s.pieces.concat().to_uppercase(), s.position)));
+    let out = render_with("import ui::greet\n[greet(\"Alice\")]", &mut ctx).unwrap();
+    assert_eq!(out, "[\"Hello, Alice!\"]")
+}
+
+#[test]
+fn import_not_reexported() {
+    let mut loader = MemoryLoader::default();
+    loader.insert("a.sdl", "let x = 1;");
+    loader.insert("b.sdl", "import a::*\nimport a::x as z\nlet y = x + z;");
+    let mut ctx = SDLContext::default();
+    ctx.set_loader(loader);
+    let out = render_with("import b::*\n[y]", &mut ctx).unwrap();
+    assert_eq!(out, "[2]");
+    let e = render_with("x", &mut ctx).unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `x` is not defined\n--> 1:1");
+    let e = render_with("import b::z", &mut ctx).unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `b::z` is not defined\n--> 1:8")
+}
+
+#[test]
+fn import_released() {
+    let marker = Rc::new(());
+    let held = marker.clone();
+    let mut loader = MemoryLoader::default();
+    loader.insert("a.sdl", "def f() { marker() }");
+    let mut ctx = SDLContext::default();
+    ctx.set_loader(loader);
+    ctx.register_raw_fn("marker", move |_, _| Ok(ASTNode::from(Rc::strong_count(&held))));
+    render_with("import a::f\nf()", &mut ctx).unwrap();
+    drop(ctx);
+    assert_eq!(Rc::strong_count(&marker), 1)
+}
+
+struct CountLoader {
+    inner: MemoryLoader,
+    count: Rc<Cell<usize>>,
+}
+
+impl ModuleLoader for CountLoader {
+    fn resolve(&self, path: &ModulePath, base: Option<&str>) -> Option<String> {
+        self.inner.resolve(path, base)
+    }
+    fn load(&self, id: &str) -> sdl_parser::Result<ASTNode> {
+        self.count.set(self.count.get() + 1);
+        self.inner.load(id)
+    }
+}
+
+#[test]
+fn import_cached() {
+    let count = Rc::new(Cell::new(0));
+    let mut inner = MemoryLoader::default();
+    inner.insert("a.sdl", "let x = 1;");
+    inner.insert("b.sdl", "import a::x\nlet y = x;");
+    let mut ctx = SDLContext::default();
+    ctx.set_loader(CountLoader { inner, count: count.clone() });
+    let out = render_with("import a\nimport b::y\nimport a::x\n[a::x, x, y]", &mut ctx).unwrap();
+    assert_eq!(out, "[1, 1, 1]");
+    assert_eq!(count.get(), 2)
+}
+
+#[test]
+fn error_circular() {
+    let mut loader = MemoryLoader::default();
+    loader.insert("a.sdl", "import b");
+    loader.insert("b.sdl", "import a");
+    let mut ctx = SDLContext::default();
+    ctx.set_loader(loader);
+    let e = render_with("import a", &mut ctx).unwrap_err();
+    assert_eq!(e.to_string(), "ImportError: Circular import `a.sdl -> b.sdl -> a.sdl`\n--> 1:8")
+}
+
+#[test]
+fn error_not_found() {
+    let e = render_with("import lib::nope", &mut site()).unwrap_err();
+    assert_eq!(e.to_string(), "FileNotFound: Module `lib::nope` is not found\n--> 1:8");
+    let e = render_with("import lib::math::nope", &mut site()).unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `lib::math::nope` is not defined\n--> 1:8")
+}
+
+#[test]
+fn error_no_loader() {
+    let e = render("import a").unwrap_err();
+    assert_eq!(e.to_string(), "ImportError: No module loader to import `a`\n--> 1:8")
+}
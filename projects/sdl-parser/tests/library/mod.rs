@@ -0,0 +1,68 @@
+use super::*;
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    list_method,
+    string_method,
+    number_method,
+];
+
+#[test]
+fn filter_with_native() {
+    let mut ctx = SDLContext::default();
+    ctx.register_fn("odd", |x: i64| Ok(x % 2 == 1));
+    let out = render_with("[1, 2, 3].filter(odd)", &mut ctx).unwrap();
+    assert_eq!(out, "[1, 3]")
+}
+
+#[test]
+fn contains_as_in() {
+    let out = render("let h = html\"a\";\n[[1].contains(1.0), 1.0 in [1], [\"a\"].contains(h), h in [\"a\"], [1].contains(2)]").unwrap();
+    assert_eq!(out, "[true, true, true, true, false]")
+}
+
+#[test]
+fn method_fallback_to_function() {
+    let out = render("def twice(s) {\n    s ++ s\n}\n[\"ab\".twice(), [1].len()]").unwrap();
+    assert_eq!(out, "[\"abab\", 1]")
+}
+
+#[test]
+fn error_method_arity() {
+    let e = render("[1, 2].join(\",\", \";\")").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Method `List.join` takes 0 to 1 arguments but 2 were given
+--> 1:7")
+}
+
+#[test]
+fn error_method_type() {
+    let e = render("\"a\".replace(1, \"b\")").unwrap_err();
+    assert_eq!(e.to_string(), "TypeError: Method `String.replace` expected `String` for argument 1, found `Integer`
+--> 1:4")
+}
+
+#[test]
+fn error_method_overflow() {
+    let e = render("(1.5).round(99999999999999999999)").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Method `Decimal.round` argument 1 is out of range, expected `Integer` from -9223372036854775808 to 9223372036854775807
+--> 1:6")
+}
+
+#[test]
+fn error_method_undefined() {
+    let e = render("[1].size()").unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `size` is not defined
+--> 1:5")
+}
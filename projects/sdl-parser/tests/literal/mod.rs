@@ -19,4 +19,49 @@ run_test![
     number,
     string, string_escape,
     template_escape,
+    escape_sequence,
 ];
+
+#[test]
+fn range_after_tab() {
+    let e = render("let x = [\n\t1 / 0,\n]").unwrap_err();
+    assert_eq!(e.to_string(), "ZeroDivisionError: Division by zero
+--> 2:4")
+}
+
+#[test]
+fn range_after_line_continuation() {
+    let e = render("let x = 'a\\\n  b' ++ \\\n  1 / 0").unwrap_err();
+    assert_eq!(e.to_string(), "ZeroDivisionError: Division by zero
+--> 3:5")
+}
+
+#[test]
+fn error_unknown_escape() {
+    let e = render("let x = 1;\nlet y = 'a\\qb'").unwrap_err();
+    assert_eq!(e.to_string(), "SyntaxError[S0003]: Unknown escape sequence `\\q`
+--> 2:11")
+}
+
+#[test]
+fn error_escape_ascii() {
+    let e = render("'\\x8F'").unwrap_err();
+    assert_eq!(e.to_string(), "SyntaxError[S0003]: Escape `\\x8F` must be at most `\\x7F`, use `\\u{8F}` instead
+--> 1:2");
+    let e = render("'\\x4'").unwrap_err();
+    assert_eq!(e.to_string(), "SyntaxError[S0003]: Escape `\\x4` expected 2 hex digits
+--> 1:2")
+}
+
+#[test]
+fn error_escape_unicode() {
+    let e = render("'\\u{D800}'").unwrap_err();
+    assert_eq!(e.to_string(), "SyntaxError[S0003]: Escape `\\u{D800}` is not a unicode scalar value
+--> 1:2");
+    let e = render("'\\u{1F600'").unwrap_err();
+    assert_eq!(e.to_string(), "SyntaxError[S0003]: Escape `\\u{1F600` is missing the closing `}`
+--> 1:2");
+    let e = render("'\\u{}'").unwrap_err();
+    assert_eq!(e.to_string(), "SyntaxError[S0003]: Escape `\\u{}` expected 1 to 6 hex digits
+--> 1:2")
+}
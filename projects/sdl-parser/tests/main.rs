@@ -1,9 +1,24 @@
+mod annotation;
 mod arithmetic;
 mod call_chain;
+mod class;
+mod compare;
+mod control;
 mod declare;
+mod dict;
 mod for_loop;
+mod function;
 mod literal;
 mod if_condition;
+mod import;
+mod library;
+mod native;
+mod slice;
+mod string_handler;
+mod symbol;
+mod syntax;
+mod template;
+mod unary;
 
 use sdl_ast::SDLContext;
 use sdl_parser::{ParserConfig, Result};
@@ -22,6 +37,14 @@ pub fn render(input: &str) -> Result<String> {
     Ok(out)
 }
 
+pub fn render_with(input: &str, ctx: &mut SDLContext) -> Result<String> {
+    let mut parser = ParserConfig::default();
+    let out = parser.parse(input)?;
+    let out = ctx.evaluate(&out)?;
+    let out = ctx.render(&out)?;
+    Ok(out)
+}
+
 pub fn render_steps(input: &str) -> Result<String> {
     let mut parser = ParserConfig::default();
     let out = parser.parse(input)?;
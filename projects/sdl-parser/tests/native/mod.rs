@@ -0,0 +1,67 @@
+use super::*;
+use sdl_ast::{ASTKind, ASTNode};
+
+fn context() -> SDLContext {
+    let mut ctx = SDLContext::default();
+    ctx.register_fn("slugify", |s: String| Ok(s.to_lowercase().replace(' ', "-")));
+    ctx.register_fn("add", |a: i64, b: i64| Ok(a + b));
+    ctx.register_fn("answer", || Ok(42));
+    ctx.register_fn("len", |xs: Vec<ASTNode>| Ok(xs.len()));
+    ctx.register_raw_fn("count", |args, _| Ok(ASTNode::from(args.arguments.len() + args.keywords.len())));
+    ctx
+}
+
+#[test]
+fn call_native() {
+    let out = render_with(r#"[slugify("Hello World"), add(1, 2), answer(), len([1, 2, 3])]"#, &mut context()).unwrap();
+    assert_eq!(out, r#"["hello-world", 3, 42, 3]"#)
+}
+
+#[test]
+fn call_native_raw() {
+    let out = render_with("[count(), count(1, 2, a = 3)]", &mut context()).unwrap();
+    assert_eq!(out, "[0, 3]")
+}
+
+#[test]
+fn native_as_value() {
+    let input = "def twice(f, x) {\n    f(f(x, 1), 1)\n}\n[twice(add, 1), (1).add(2)]";
+    let out = render_with(input, &mut context()).unwrap();
+    assert_eq!(out, "[3, 3]")
+}
+
+#[test]
+fn native_nullable_argument() {
+    let mut ctx = SDLContext::default();
+    ctx.register_fn("or_zero", |x: Option<i64>| Ok(ASTKind::from(x.unwrap_or(0))));
+    let out = render_with("[or_zero(null), or_zero(7)]", &mut ctx).unwrap();
+    assert_eq!(out, "[0, 7]")
+}
+
+#[test]
+fn error_native_arity() {
+    let e = render_with("add(1)", &mut context()).unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Function `add` takes 2 arguments but 1 were given
+--> 1:4")
+}
+
+#[test]
+fn error_native_type() {
+    let e = render_with("[1, add(1, \"2\")]", &mut context()).unwrap_err();
+    assert_eq!(e.to_string(), "TypeError: Function `add` expected `Integer` for argument 2, found `String`
+--> 1:8")
+}
+
+#[test]
+fn error_native_overflow() {
+    let e = render_with("add(1, 99999999999999999999)", &mut context()).unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Function `add` argument 2 is out of range, expected `Integer` from -9223372036854775808 to 9223372036854775807
+--> 1:4")
+}
+
+#[test]
+fn error_native_keyword() {
+    let e = render_with("slugify(s = \"a\")", &mut context()).unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Function `slugify` got an unexpected keyword argument `s`
+--> 1:9")
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    slice_list,
+    slice_string,
+    slice_step,
+    slice_matrix,
+];
+
+#[test]
+fn error_index_out_of_range() {
+    let e = render("[1, 2, 3][4]").unwrap_err();
+    assert_eq!(e.to_string(), "IndexError: Unable to get index 4 on type `List`
+--> 1:10")
+}
+
+#[test]
+fn error_index_zero() {
+    let e = render("\"abc\"[0]").unwrap_err();
+    assert_eq!(e.to_string(), "IndexError: Unable to get index 0 on type `String`
+--> 1:6")
+}
+
+#[test]
+fn error_step_zero() {
+    let e = render("[1, 2, 3][1:3:0]").unwrap_err();
+    assert_eq!(e.to_string(), "IndexError: Unable to get index 1:3:0 on type `List`
+--> 1:10")
+}
+
+#[test]
+fn error_slice_type() {
+    let e = render("[1, 2, 3][1:\"a\"]").unwrap_err();
+    assert_eq!(e.to_string(), "IndexError: Unable to get index 1:\"a\" on type `List`
+--> 1:10")
+}
+
+#[test]
+fn error_slice_of_integer() {
+    let e = render("123[1:2]").unwrap_err();
+    assert_eq!(e.to_string(), "IndexError: Unable to get index 1:2 on type `Integer`
+--> 1:4")
+}
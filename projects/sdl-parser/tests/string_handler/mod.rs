@@ -0,0 +1,76 @@
+use super::*;
+use sdl_ast::ASTNode;
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    tagged,
+    markdown,
+    html_attribute,
+    markdown_value,
+];
+
+#[test]
+fn empty_string() {
+    assert_eq!(render("[raw'', html``]").unwrap(), "[, ]")
+}
+
+#[test]
+fn regex_escape() {
+    assert_eq!(render("let ext = '.rs';\nre'^\\\\w+{{ ext }}$'").unwrap(), r#""^\\w+\\.rs$""#)
+}
+
+#[test]
+fn handler_function() {
+    let out = render("def tag(pieces, values) { [pieces, values] }\ntag'a{{ 1 }}b{{ 2 }}'").unwrap();
+    assert_eq!(out, r#"[["a", "b", ""], [1, 2]]"#)
+}
+
+#[test]
+fn handler_host() {
+    let mut ctx = SDLContext::default();
+    ctx.register_string_handler("upper", |s, _| {
+        let values: Vec<String> = s.values.iter().map(|v| v.as_string().to_uppercase()).collect();
+        Ok(ASTNode::string(format!("{}{}{}", s.pieces.join("|"), s.handler, values.join("|")), s.position))
+    });
+    let out = render_with("let x = 'y';\nupper'a{{ x }}b'", &mut ctx).unwrap();
+    assert_eq!(out, r#""a|bupperY""#)
+}
+
+#[test]
+fn handler_shadow_builtin() {
+    let mut ctx = SDLContext::default();
+    ctx.register_string_handler("raw", |s, _| Ok(ASTNode::string(s.pieces.concat(), s.position)));
+    assert_eq!(render_with("raw'<b>'", &mut ctx).unwrap(), r#""<b>""#)
+}
+
+#[test]
+fn error_undefined_handler() {
+    let e = render("let x = nope'a'").unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `nope` is not defined
+--> 1:9")
+}
+
+#[test]
+fn error_invalid_regex() {
+    let e = render("re'(a'").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: String handler `re` got an invalid pattern, unclosed group
+--> 1:1")
+}
+
+#[test]
+fn html_value_in_attribute() {
+    let out = render("let q = '\" onmouseover=\"alert(1)'; let u = html\"{{ q }}\"; <a title=u>x</a>").unwrap();
+    assert_eq!(out, "<a title=\"&quot; onmouseover=&quot;alert(1)\">x</a>")
+}
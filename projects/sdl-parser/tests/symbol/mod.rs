@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn lookup_inserted() {
+    let mut ctx = SDLContext::default();
+    ctx.insert("x", 1);
+    ctx.insert("xs", vec![2.into(), 3.into()]);
+    let out = render_with("[x, xs, xs.1]", &mut ctx).unwrap();
+    assert_eq!(out, "[1, [2, 3], 2]")
+}
+
+#[test]
+fn lookup_outer_scope() {
+    let mut ctx = SDLContext::default();
+    ctx.insert("x", 1);
+    let out = render_with("for i in [1, 2] { [x, i] }", &mut ctx).unwrap();
+    assert_eq!(out, "[1, 1][1, 2]")
+}
+
+#[test]
+fn lookup_namespace() {
+    let mut site = SDLContext::default();
+    site.insert("title", 1);
+    let mut meta = SDLContext::default();
+    meta.insert("version", 2);
+    site.insert_module("meta", meta);
+    let mut ctx = SDLContext::default();
+    ctx.insert_module("site", site);
+    let out = render_with("[site::title, site::meta::version]", &mut ctx).unwrap();
+    assert_eq!(out, "[1, 2]")
+}
+
+#[test]
+fn error_undefined_symbol() {
+    let e = render("[1, x]").unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `x` is not defined
+--> 1:5")
+}
+
+#[test]
+fn error_undefined_namespace() {
+    let e = render("for i in [1] { site::title }").unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `site::title` is not defined
+--> 1:16")
+}
+
+#[test]
+fn error_scope_dropped() {
+    let e = render("for i in [1] { i }\ni").unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `i` is not defined
+--> 2:1")
+}
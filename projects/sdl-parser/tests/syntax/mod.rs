@@ -0,0 +1,91 @@
+use super::*;
+use sdl_parser::{SDLErrorKind, SyntaxErrorCode};
+use std::{fs, path::Path};
+
+fn parse_error(input: &str) -> (SyntaxErrorCode, Vec<String>, String) {
+    let e = ParserConfig::default().parse(input).unwrap_err();
+    match e.kind() {
+        SDLErrorKind::SyntaxError { code, expected, .. } => (*code, expected.to_owned(), e.to_string()),
+        _ => panic!("expected a syntax error, found {}", e),
+    }
+}
+
+#[test]
+fn unexpected_end() {
+    let (code, expected, msg) = parse_error("let x = (1 +");
+    assert_eq!(code, SyntaxErrorCode::UnexpectedToken);
+    assert_eq!(code.as_str(), "S0001");
+    assert_eq!(expected, ["term"]);
+    assert_eq!(msg, "SyntaxError[S0001]: Expected `term`, found end of input
+--> 1:13")
+}
+
+#[test]
+fn unexpected_token() {
+    let (_, expected, msg) = parse_error("<a>\n  text\n</b>");
+    assert_eq!(expected, ["HTMLBadTag", "text_mode", "Symbol"]);
+    assert_eq!(msg, "SyntaxError[S0001]: Expected `HTMLBadTag`, `text_mode` or `Symbol`, found `/`
+--> 3:2")
+}
+
+#[test]
+fn unclosed_string() {
+    let (_, expected, _) = parse_error("'abc");
+    assert_eq!(expected, ["NS2", "StringEscape", "S2"])
+}
+
+/// Pest runs out of stack before the parser sees the input, where depends on the stack of the thread.
+#[test]
+fn deep_nesting() {
+    let input = format!("{}1{}", "[".repeat(5000), "]".repeat(5000));
+    let (code, _, msg) = parse_error(&input);
+    assert_eq!(code, SyntaxErrorCode::NestingTooDeep);
+    assert_eq!(code.as_str(), "S0004");
+    assert!(msg.starts_with("SyntaxError[S0004]: Nesting is too deep to be parsed\n--> 1:"), "{}", msg)
+}
+
+/// `n` lists hold `n + 1` expressions, the default limit is 128.
+#[test]
+fn nesting_limit() {
+    let nested = |n: usize| format!("{}1{}", "[".repeat(n), "]".repeat(n));
+    assert!(ParserConfig::default().parse(nested(126)).is_ok());
+    assert!(ParserConfig::default().parse(nested(127)).is_ok());
+    let (code, _, msg) = parse_error(&nested(128));
+    assert_eq!(code, SyntaxErrorCode::NestingTooDeep);
+    assert_eq!(msg, "SyntaxError[S0004]: Nesting is deeper than the limit of 128
+--> 1:129")
+}
+
+#[test]
+fn nesting_limit_config() {
+    let input = "if true { if true { 1 } else { 2 } } else { <p><b>3</b></p> }";
+    let mut parser = ParserConfig::default();
+    parser.max_depth = 4;
+    assert!(parser.parse(input).is_ok());
+    parser.max_depth = 3;
+    let e = parser.parse(input).unwrap_err();
+    assert_eq!(e.to_string(), "SyntaxError[S0004]: Nesting is deeper than the limit of 3
+--> 1:48")
+}
+
+/// Truncated sources are the most common input of an editor, none of them may panic.
+#[test]
+fn truncated_fixtures() {
+    let mut stack = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")];
+    while let Some(dir) = stack.pop() {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            if path.extension().is_none_or(|e| e != "sdl") {
+                continue;
+            }
+            let source = fs::read_to_string(&path).unwrap();
+            for (i, _) in source.char_indices().step_by(8) {
+                let _ = ParserConfig::default().parse(&source[..i]);
+            }
+        }
+    }
+}
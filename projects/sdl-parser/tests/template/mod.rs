@@ -0,0 +1,120 @@
+use super::*;
+use sdl_ast::ASTKind;
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    element_basic,
+    element_class,
+    element_void,
+    escape_text,
+    escape_safe,
+    escape_url,
+    escape_html_attribute,
+    page,
+    sdl_mode,
+    text_basic,
+    text_whitespace,
+    text_inline,
+    text_entity,
+    text_raw,
+    component_basic,
+    component_class,
+    layout_extend,
+    layout_helper,
+];
+
+#[test]
+fn element_tree() {
+    let mut parser = ParserConfig::default();
+    let ast = parser.parse(r#"<a href="/" class="x y x">home<b>!</b></a>"#).unwrap();
+    let out = SDLContext::default().evaluate(&ast).unwrap();
+    let html = match out.kind {
+        ASTKind::Block(v) => match &v[0].kind {
+            ASTKind::HTMLElement(e) => e.to_owned(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(html.tag, "a");
+    assert_eq!(html.class.iter().collect::<Vec<_>>(), ["x", "y"]);
+    assert_eq!(html.arguments.get("href").unwrap().kind, ASTKind::from("/"));
+    assert_eq!(html.children.len(), 2);
+    assert_eq!(html.children[0].kind, ASTKind::HTMLText(String::from("home")));
+}
+
+#[test]
+fn error_void_children() {
+    let e = render("<br>x</br>").unwrap_err();
+    assert_eq!(e.to_string(), "TemplateError: Void element `<br>` cannot have children\n--> 1:2")
+}
+
+#[test]
+fn error_class_type() {
+    let e = render("<p class=1></p>").unwrap_err();
+    assert_eq!(e.to_string(), "TypeError: Attribute `class` expected `String`, `List` or `Dict`, found `Integer`\n--> 1:10")
+}
+
+#[test]
+fn string_literal() {
+    let out = render("['a\"b', 'line\nbreak', '<&>']").unwrap();
+    assert_eq!(out, r#"["a\"b", "line\nbreak", "<&>"]"#)
+}
+
+#[test]
+fn component_namespace() {
+    let mut ui = SDLContext::default();
+    render_with("def Badge(text) { <span class=\"badge\">{{ text }}</span> }", &mut ui).unwrap();
+    let mut ctx = SDLContext::default();
+    ctx.insert_module("ui", ui);
+    let out = render_with("<ul><li><ui::Badge text=1/></li></ul>", &mut ctx).unwrap();
+    assert_eq!(out, r#"<ul><li><span class="badge">1</span></li></ul>"#)
+}
+
+#[test]
+fn error_component_keyword() {
+    let e = render("def Icon(name) { name }\n<Icon name=\"x\">child</Icon>").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Function `Icon` got an unexpected keyword argument `children`\n--> 2:2")
+}
+
+#[test]
+fn error_component_undefined() {
+    let e = render("<ui::Button/>").unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `ui::Button` is not defined\n--> 1:2")
+}
+
+#[test]
+fn error_component_capitalized() {
+    let e = render("<div>\n    <Foo/>\n</div>").unwrap_err();
+    assert_eq!(e.to_string(), "NameError: Symbol `Foo` is not defined\n--> 2:6")
+}
+
+#[test]
+fn error_component_class_render() {
+    let e = render("class Point { x }\n<Point x=1/>").unwrap_err();
+    assert_eq!(e.to_string(), "TemplateError: Class `Point` has no `render` method to be used as a component
+--> 2:2")
+}
+
+#[test]
+fn error_layout_region() {
+    let e = render("def Base(title) { <title>{{ title }}</title> }\nextend Base with {\n    let titel = \"typo\";\n}").unwrap_err();
+    assert_eq!(e.to_string(), "ArgumentError: Function `Base` got an unexpected keyword argument `titel`\n--> 3:17")
+}
+
+#[test]
+fn error_layout_block() {
+    let e = render("def Base(body) { body }\nextend Base with {\n    let body = <p>{{ 1 + \"x\" }}</p>;\n}").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Cannot apply `+` to `Integer` and `String`\n--> 3:24")
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+macro_rules! run_test {
+    ($($F:ident), +,) => {
+        $(run_test![$F, stringify!($F)];)+
+    };
+    ($function_name:ident, $file_name:expr) => {
+    #[test]
+    fn $function_name() {
+        let out = render(include_str!(concat!($file_name, ".sdl"))).unwrap();
+        assert_eq!(out, include_str!(concat!($file_name, ".out.sdl")))
+    }
+    };
+}
+
+run_test![
+    prefix_basic,
+    spread,
+    suffix_try,
+];
+
+#[test]
+fn spread_call() {
+    let out = render("def add(a, b, c) {a + b + c}\nadd(1, *[2, 3])").unwrap();
+    assert_eq!(out, "6")
+}
+
+#[test]
+fn suffix_unwrap() {
+    let out = render("let x = [1, 2];\nx!.len()").unwrap();
+    assert_eq!(out, "2")
+}
+
+#[test]
+fn error_unwrap_null() {
+    let e = render("let x = null;\nx!").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Expected a value but found `null`
+--> 2:2")
+}
+
+#[test]
+fn error_prefix_type() {
+    let e = render("-\"a\"").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Cannot apply prefix `-` to type `String`
+--> 1:1")
+}
+
+#[test]
+fn error_spread_type() {
+    let e = render("[1, *2]").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Spread `*` expects a `List`, found `Integer`
+--> 1:5")
+}
+
+#[test]
+fn error_spread_outside() {
+    let e = render("*[1]").unwrap_err();
+    assert_eq!(e.to_string(), "OperationError: Spread `*` is only allowed inside lists and calls
+--> 1:1")
+}
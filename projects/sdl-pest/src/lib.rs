@@ -4,7 +4,7 @@ pub use pest::{
     self,
     error::Error,
     iterators::{Pair, Pairs},
-    prec_climber::{Assoc, Operator, PrecClimber},
+    pratt_parser::{Assoc, Op, PrattParser},
     Parser, Span,
 };
 pub use sdl::{Rule, SDLParser};
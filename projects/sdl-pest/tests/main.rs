@@ -13,13 +13,14 @@ fn ready() {
 #[test]
 #[ignore]
 pub fn gen_sdl_lexer() {
-    let pest = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "./sdl.pest"));
-    let rs = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "./src/sdl.rs"));
+    let pest = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/sdl.pest"));
+    let rs = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/sdl.rs"));
 
     let derived = {
-        let path = pest.to_string_lossy();
+        // `//!` marks are highlight hints, not grammar docs
+        let grammar = std::fs::read_to_string(pest).unwrap().replace("//!", "//");
         let pest = quote! {
-            #[grammar = #path]
+            #[grammar_inline = #grammar]
             pub struct SDLParser;
         };
         derive_parser(pest, false)